@@ -0,0 +1,393 @@
+using System.Text.Json;
+using Microsoft.Extensions.Logging;
+using Winterflood.RuleEngine.Compiler.Compiler;
+using Winterflood.RuleEngine.Compiler.Configuration;
+using Winterflood.RuleEngine.Compiler.Configuration.Models;
+using Winterflood.RuleEngine.Compiler.Runners;
+
+namespace Winterflood.RuleEngine.Cli;
+
+/// <summary>
+/// Command line entry point for checking and running rule engine configurations.
+/// </summary>
+public static class Program
+{
+    private const int ExitSuccess = 0;
+    private const int ExitFailure = 1;
+    private const int ExitUsage = 2;
+
+    private const string EvalDataType = "EvalData";
+    private const string EvalRuleSetName = "EvalRuleSet";
+    private const string EvalRuleName = "EvalExpression";
+    private const string EvalValueKey = "EvalValue";
+
+    private static readonly string[] Commands = ["check", "run", "lint", "eval"];
+
+    private const string Usage =
+        """
+        Usage:
+          rule-engine check <config> [--env <name>] [--verbose]
+              Compiles the configuration and runs the tests defined on each ruleset.
+
+          rule-engine run <config> --ruleset <name> [--env <name>] [--data <data.json>] [--trace] [--verbose]
+              Compiles the configuration and evaluates a ruleset against the given data.
+
+          rule-engine lint <config> [--entry <name>]... [--output <member>]... [--env <name>]
+              Reports unreachable rules, undefined references, rulesets not reachable from the entry points,
+              and data members that are written but neither read nor declared as outputs.
+
+          rule-engine eval "<expression>" [--config <config> --type <name>] [--data <data.json>] [--verbose]
+              Evaluates a C# expression, optionally against data of a type defined in the configuration,
+              and prints the value as JSON. The data is available to the expression as 'data'.
+
+          <config> is a configuration file, or a directory whose *.json files are merged into one configuration.
+          --env <name> selects the ruleset variants of the given environment, and is required by check and run
+          when the configuration defines variants.
+        """;
+
+    /// <summary>
+    /// Runs the command described by <paramref name="args"/>.
+    /// </summary>
+    /// <param name="args">The command line arguments.</param>
+    /// <returns>The process exit code.</returns>
+    public static int Main(string[] args) => Run(args, Console.Out, Console.Error);
+
+    /// <summary>
+    /// Runs the command described by <paramref name="args"/>, writing its results to the given writers.
+    /// </summary>
+    /// <param name="args">The command line arguments.</param>
+    /// <param name="output">The writer results are written to.</param>
+    /// <param name="error">The writer errors and usage are written to.</param>
+    /// <param name="cache">
+    /// Optional cache to reuse compilations from, for hosts running several commands; every command compiles
+    /// its configuration when omitted.
+    /// </param>
+    /// <returns>The process exit code.</returns>
+    public static int Run(string[] args, TextWriter output, TextWriter error, CompilationCache? cache = null)
+    {
+        // Validate the command before touching the file system so that mistakes show the usage
+        if (args.Length < 2 || !Commands.Contains(args[0]))
+            return PrintUsage(error);
+
+        using var loggerFactory = LoggerFactory.Create(builder =>
+        {
+            builder.AddSimpleConsole(options =>
+            {
+                options.SingleLine = true;
+                options.TimestampFormat = "hh:mm:ss ";
+            });
+            builder.SetMinimumLevel(HasFlag(args, "--verbose") ? LogLevel.Information : LogLevel.Warning);
+        });
+
+        if (args[0] == "eval")
+            return Eval(args, output, error, loggerFactory, cache);
+
+        var configuration = LoadConfiguration(args[1], error, loggerFactory);
+        if (configuration is null)
+            return ExitFailure;
+
+        var environment = GetOption(args, "--env");
+        if (environment is not null)
+        {
+            configuration = SelectEnvironment(configuration, environment, error);
+            if (configuration is null)
+                return ExitFailure;
+        }
+        else if (args[0] != "lint" && RuleSetEnvironmentResolver.RequiresEnvironment(configuration, out var variantError))
+        {
+            // Lint reads the configuration as written, but check and run must compile exactly one variant of each ruleset
+            error.WriteLine($"[Invalid Environment] {variantError}");
+            error.WriteLine("Select the environment to compile with --env <name>.");
+            return ExitFailure;
+        }
+
+        return args[0] switch
+        {
+            "check" => Check(configuration, output, error, loggerFactory, cache),
+            "run" => RunRuleSet(configuration, args, output, error, loggerFactory, cache),
+            "lint" => Lint(configuration, args, output),
+            _ => PrintUsage(error)
+        };
+    }
+
+    private static int Check(
+        RuleEngineConfiguration configuration,
+        TextWriter output,
+        TextWriter error,
+        ILoggerFactory loggerFactory,
+        CompilationCache? cache)
+    {
+        var compilation = CompileConfiguration(configuration, error, loggerFactory, cache);
+        if (compilation is null)
+            return ExitFailure;
+
+        var passed = TestRunner.RunTests(compilation.CompiledAssembly!, configuration, loggerFactory);
+
+        output.WriteLine(passed ? "All tests passed." : "One or more tests failed.");
+        return passed ? ExitSuccess : ExitFailure;
+    }
+
+    private static int RunRuleSet(
+        RuleEngineConfiguration configuration,
+        string[] args,
+        TextWriter output,
+        TextWriter error,
+        ILoggerFactory loggerFactory,
+        CompilationCache? cache)
+    {
+        var ruleSetName = GetOption(args, "--ruleset");
+        if (ruleSetName is null)
+            return PrintUsage(error);
+
+        var ruleSet = configuration.RuleSets.FirstOrDefault(r => r.Name == ruleSetName);
+        if (ruleSet is null)
+        {
+            error.WriteLine($"RuleSet '{ruleSetName}' is not defined in the configuration.");
+            return ExitFailure;
+        }
+
+        if (!TryReadData(GetOption(args, "--data"), error, out var jsonData))
+            return ExitFailure;
+
+        var compilation = CompileConfiguration(configuration, error, loggerFactory, cache);
+        if (compilation is null)
+            return ExitFailure;
+
+        var execution =
+            RuleRunner.ExecuteRulesetDetailed(
+                loggerFactory,
+                compilation.CompiledAssembly!,
+                ruleSet.Name,
+                ruleSet.DataType,
+                jsonData);
+
+        // The trace is written even when the evaluation failed, so the rules evaluated before the error are visible
+        if (HasFlag(args, "--trace"))
+            output.WriteLine(JsonSerializer.Serialize(execution.Context, new JsonSerializerOptions { WriteIndented = true }));
+
+        var result = execution.Result;
+        if (result is null)
+        {
+            error.WriteLine(
+                execution.FailedRule is null
+                    ? $"RuleSet '{ruleSetName}' could not be executed."
+                    : $"RuleSet '{ruleSetName}' failed at Rule '{execution.FailedRule.RuleName}': {execution.Error}");
+            return ExitFailure;
+        }
+
+        output.WriteLine($"RuleSet={ruleSet.Name} Result={(result.Value ? "PASSED" : "FAILED")}");
+        return result.Value ? ExitSuccess : ExitFailure;
+    }
+
+    private static int Lint(RuleEngineConfiguration configuration, string[] args, TextWriter output)
+    {
+        var entryPoints = GetOptions(args, "--entry");
+        var outputs = GetOptions(args, "--output");
+
+        var findings = DeadRuleAnalyzer.Analyze(configuration, entryPoints, outputs);
+        foreach (var finding in findings)
+            output.WriteLine($"[Dead Rule] {finding}");
+
+        output.WriteLine(findings.Count == 0 ? "No findings." : $"{findings.Count} finding(s).");
+        return findings.Count == 0 ? ExitSuccess : ExitFailure;
+    }
+
+    private static int Eval(
+        string[] args,
+        TextWriter output,
+        TextWriter error,
+        ILoggerFactory loggerFactory,
+        CompilationCache? cache)
+    {
+        var expression = args[1];
+        var configPath = GetOption(args, "--config");
+        var dataType = GetOption(args, "--type");
+        var dataPath = GetOption(args, "--data");
+
+        // Data can only be bound to a type defined in a configuration
+        if ((configPath is null) != (dataType is null) || (dataPath is not null && dataType is null))
+            return PrintUsage(error);
+
+        var types = new List<DataModelDefinition>();
+        if (configPath is not null)
+        {
+            var configuration = LoadConfiguration(configPath, error, loggerFactory);
+            if (configuration is null)
+                return ExitFailure;
+
+            types.AddRange(configuration.Types);
+        }
+        else
+        {
+            types.Add(new DataModelDefinition { Name = EvalDataType });
+        }
+
+        if (!TryReadData(dataPath, error, out var jsonData))
+            return ExitFailure;
+
+        // The expression is compiled as the condition of a rule, which is parsed as a single expression rather than
+        // split into statements like rule actions, so semicolons within it, such as in string literals, are kept
+        var evalConfiguration = new RuleEngineConfiguration
+        {
+            Types = types,
+            RuleSets =
+            [
+                new RuleSetDefinition
+                {
+                    Name = EvalRuleSetName,
+                    DataType = dataType ?? EvalDataType,
+                    Rules =
+                    [
+                        new StandardRuleDefinition
+                        {
+                            RuleName = EvalRuleName,
+                            Conditions = $"ctx.ChildContexts.TryAdd(\"{EvalValueKey}\", (object)({expression}))"
+                        }
+                    ]
+                }
+            ]
+        };
+
+        var compilation = CompileConfiguration(evalConfiguration, error, loggerFactory, cache);
+        if (compilation is null)
+            return ExitFailure;
+
+        var execution =
+            RuleRunner.ExecuteRulesetDetailed(
+                loggerFactory,
+                compilation.CompiledAssembly!,
+                EvalRuleSetName,
+                dataType ?? EvalDataType,
+                jsonData);
+
+        if (execution.Result is null)
+        {
+            error.WriteLine(
+                execution.Error is null
+                    ? "The expression could not be evaluated."
+                    : $"The expression failed: {execution.Error}");
+            return ExitFailure;
+        }
+
+        var value = execution.Context.GetChildContext<object>(EvalValueKey);
+        output.WriteLine(JsonSerializer.Serialize(value, new JsonSerializerOptions { WriteIndented = true }));
+        return ExitSuccess;
+    }
+
+    private static RuleEngineConfiguration? LoadConfiguration(string path, TextWriter error, ILoggerFactory loggerFactory)
+    {
+        if (Directory.Exists(path))
+            return LoadConfigurationDirectory(path, error, loggerFactory);
+
+        if (!File.Exists(path))
+        {
+            error.WriteLine($"Configuration file '{path}' does not exist.");
+            return null;
+        }
+
+        var configuration = RuleDefinitionParser.ParseConfiguration(File.ReadAllText(path), loggerFactory);
+        if (configuration is null)
+            error.WriteLine($"Configuration file '{path}' could not be parsed.");
+
+        return configuration;
+    }
+
+    private static RuleEngineConfiguration? LoadConfigurationDirectory(
+        string directory,
+        TextWriter error,
+        ILoggerFactory loggerFactory)
+    {
+        var files =
+            Directory
+                .EnumerateFiles(directory, "*.json", SearchOption.AllDirectories)
+                .OrderBy(file => file, StringComparer.Ordinal)
+                .ToList();
+
+        if (files.Count == 0)
+        {
+            error.WriteLine($"Configuration directory '{directory}' does not contain any *.json files.");
+            return null;
+        }
+
+        var configurations = new List<RuleEngineConfiguration>();
+        foreach (var file in files)
+        {
+            var configuration = LoadConfiguration(file, error, loggerFactory);
+            if (configuration is null)
+                return null;
+
+            configurations.Add(configuration);
+        }
+
+        return RuleDefinitionParser.MergeConfigurations(configurations);
+    }
+
+    private static bool TryReadData(string? path, TextWriter error, out string? jsonData)
+    {
+        jsonData = null;
+        if (path is null)
+            return true;
+
+        if (!File.Exists(path))
+        {
+            error.WriteLine($"Data file '{path}' does not exist.");
+            return false;
+        }
+
+        jsonData = File.ReadAllText(path);
+        return true;
+    }
+
+    private static RuleEngineConfiguration? SelectEnvironment(
+        RuleEngineConfiguration configuration,
+        string environment,
+        TextWriter error)
+    {
+        if (!configuration.Environments.Contains(environment))
+        {
+            error.WriteLine($"Environment '{environment}' is not declared in the configuration.");
+            return null;
+        }
+
+        var errors = RuleSetEnvironmentResolver.Validate(configuration);
+        foreach (var validationError in errors)
+            error.WriteLine($"[Invalid Environment] {validationError}");
+
+        return errors.Count == 0 ? RuleSetEnvironmentResolver.ForEnvironment(configuration, environment) : null;
+    }
+
+    private static CompilationResult? CompileConfiguration(
+        RuleEngineConfiguration configuration,
+        TextWriter error,
+        ILoggerFactory loggerFactory,
+        CompilationCache? cache)
+    {
+        var compilation =
+            cache?.GetOrCompile(configuration, loggerFactory)
+            ?? SyntaxTreeCompiler.Compile(configuration, loggerFactory);
+        if (compilation.Success && compilation.CompiledAssembly is not null)
+            return compilation;
+
+        foreach (var unit in compilation.UnitResults.Where(unit => !unit.Success))
+            error.WriteLine($"[Compilation Failed] {unit.Type}: {unit.Message}");
+
+        return null;
+    }
+
+    private static string? GetOption(string[] args, string name)
+    {
+        var index = Array.IndexOf(args, name);
+        return index >= 0 && index + 1 < args.Length ? args[index + 1] : null;
+    }
+
+    private static IEnumerable<string> GetOptions(string[] args, string name)
+        => args.Where((_, i) => i > 0 && args[i - 1] == name);
+
+    private static bool HasFlag(string[] args, string name) => args.Contains(name);
+
+    private static int PrintUsage(TextWriter error)
+    {
+        error.WriteLine(Usage);
+        return ExitUsage;
+    }
+}
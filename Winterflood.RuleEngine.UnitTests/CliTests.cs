@@ -0,0 +1,212 @@
+using System.Text.Json;
+using Microsoft.Extensions.Logging.Abstractions;
+using Winterflood.RuleEngine.Cli;
+using Winterflood.RuleEngine.Compiler.Configuration;
+using Winterflood.RuleEngine.Compiler.Configuration.Models;
+using Xunit;
+using Assert = Xunit.Assert;
+
+namespace Winterflood.RuleEngine.UnitTests;
+
+public class CliTests : IDisposable
+{
+    private readonly string _directory = Path.Combine(Path.GetTempPath(), $"cli_{Guid.NewGuid():N}");
+
+    public CliTests()
+    {
+        Directory.CreateDirectory(_directory);
+    }
+
+    public void Dispose()
+    {
+        if (Directory.Exists(_directory))
+            Directory.Delete(_directory, true);
+    }
+
+    private static (int ExitCode, string Output, string Error) Run(params string[] args)
+    {
+        var output = new StringWriter();
+        var error = new StringWriter();
+        var exitCode = Program.Run(args, output, error);
+        return (exitCode, output.ToString(), error.ToString());
+    }
+
+    private string WriteFile(string fileName, string content)
+    {
+        var path = Path.Combine(_directory, fileName);
+        File.WriteAllText(path, content);
+        return path;
+    }
+
+    private void WriteConfigurationFiles()
+    {
+        var options = RuleDefinitionParser.CreateSerializerOptions(NullLoggerFactory.Instance);
+
+        WriteFile("types.json", JsonSerializer.Serialize(new RuleEngineConfiguration
+        {
+            Types =
+            [
+                new DataModelDefinition
+                {
+                    Name = "LoanApplication",
+                    Fields = [new FieldDefinition { Name = "Amount", Type = "int" }]
+                }
+            ]
+        }, options));
+
+        WriteFile("rules.json", JsonSerializer.Serialize(new RuleEngineConfiguration
+        {
+            RuleSets =
+            [
+                new RuleSetDefinition
+                {
+                    Name = "LoanDecision",
+                    DataType = "LoanApplication",
+                    Rules = [new StandardRuleDefinition { RuleName = "AmountWithinLimit", Conditions = "data.Amount <= 1000" }],
+                    Tests =
+                    [
+                        new RuleTestDefinition
+                        {
+                            Data = JsonDocument.Parse("""{ "Amount": 500 }""").RootElement,
+                            Expect = "data.Amount == 500"
+                        }
+                    ]
+                }
+            ]
+        }, options));
+    }
+
+    [Fact]
+    public void Run_UnknownCommand_PrintsUsageWithoutReadingConfiguration()
+    {
+        var (exitCode, _, error) = Run("frobnicate", "missing.json");
+
+        Assert.Equal(2, exitCode);
+        Assert.Contains("Usage:", error);
+        Assert.DoesNotContain("does not exist", error);
+    }
+
+    [Fact]
+    public void Check_Directory_MergesConfigurationFiles()
+    {
+        WriteConfigurationFiles();
+
+        var (exitCode, output, _) = Run("check", _directory);
+
+        Assert.Equal(0, exitCode);
+        Assert.Contains("All tests passed.", output);
+    }
+
+    [Fact]
+    public void Run_FailingRuleSet_ReportsResult()
+    {
+        WriteConfigurationFiles();
+        var dataPath = WriteFile("data.txt", """{ "Amount": 5000 }""");
+
+        var (exitCode, output, _) = Run("run", _directory, "--ruleset", "LoanDecision", "--data", dataPath);
+
+        Assert.Equal(1, exitCode);
+        Assert.Contains("RuleSet=LoanDecision Result=FAILED", output);
+    }
+
+    [Fact]
+    public void Eval_WithoutConfiguration_PrintsValue()
+    {
+        var (exitCode, output, _) = Run("eval", "Math.Max(2, 3) * 10");
+
+        Assert.Equal(0, exitCode);
+        Assert.Equal("30", output.Trim());
+    }
+
+    [Fact]
+    public void Run_MissingDataFile_ReportsError()
+    {
+        WriteConfigurationFiles();
+        var dataPath = Path.Combine(_directory, "missing.txt");
+
+        var (exitCode, _, error) = Run("run", _directory, "--ruleset", "LoanDecision", "--data", dataPath);
+
+        Assert.Equal(1, exitCode);
+        Assert.Contains($"Data file '{dataPath}' does not exist.", error);
+    }
+
+    [Fact]
+    public void Eval_ExpressionWithSemicolon_PrintsValue()
+    {
+        var (exitCode, output, _) = Run("eval", "\"a;b\".Split(';').Length");
+
+        Assert.Equal(0, exitCode);
+        Assert.Equal("2", output.Trim());
+    }
+
+    [Fact]
+    public void Eval_WithData_EvaluatesAgainstData()
+    {
+        WriteConfigurationFiles();
+        var dataPath = WriteFile("data.txt", """{ "Amount": 500 }""");
+
+        var (exitCode, output, _) = Run("eval", "data.Amount * 2", "--config", _directory, "--type", "LoanApplication", "--data", dataPath);
+
+        Assert.Equal(0, exitCode);
+        Assert.Equal("1000", output.Trim());
+    }
+
+    [Fact]
+    public void Check_VariantsWithoutEnvironment_RequiresEnvironment()
+    {
+        WriteConfigurationFiles();
+        WriteFile("prod.json", JsonSerializer.Serialize(new RuleEngineConfiguration
+        {
+            Environments = ["prod"],
+            RuleSets =
+            [
+                new RuleSetDefinition
+                {
+                    Name = "LoanDecision",
+                    DataType = "LoanApplication",
+                    Environments = ["prod"],
+                    Rules = [new StandardRuleDefinition { RuleName = "AmountWithinLimit", Conditions = "data.Amount <= 500" }]
+                }
+            ]
+        }, RuleDefinitionParser.CreateSerializerOptions(NullLoggerFactory.Instance)));
+
+        var (exitCode, _, error) = Run("check", _directory);
+        var (prodExitCode, prodOutput, _) = Run("check", _directory, "--env", "prod");
+
+        Assert.Equal(1, exitCode);
+        Assert.Contains("RuleSet 'LoanDecision' has variants", error);
+        Assert.Contains("--env", error);
+        Assert.Equal(0, prodExitCode);
+        Assert.Contains("All tests passed.", prodOutput);
+    }
+
+    [Fact]
+    public void Lint_Outputs_ReportsOnlyUnreadIntermediateMembers()
+    {
+        var path = WriteFile("rules.json", JsonSerializer.Serialize(new RuleEngineConfiguration
+        {
+            RuleSets =
+            [
+                new RuleSetDefinition
+                {
+                    Name = "LoanDecision",
+                    DataType = "LoanApplication",
+                    Rules =
+                    [
+                        new StandardRuleDefinition { RuleName = "Score", Conditions = "true", OnSuccess = "data.Score = data.Amount / 10; data.Legacy = 1" },
+                        new StandardRuleDefinition { RuleName = "Approve", Conditions = "data.Score < 50", OnSuccess = "data.Approved = true" }
+                    ]
+                }
+            ]
+        }, RuleDefinitionParser.CreateSerializerOptions(NullLoggerFactory.Instance)));
+
+        var (exitCode, output, _) = Run("lint", path, "--entry", "LoanDecision");
+        var (outputsExitCode, outputsOutput, _) = Run("lint", path, "--entry", "LoanDecision", "--output", "Approved");
+
+        Assert.Equal(0, exitCode);
+        Assert.Contains("No findings.", output);
+        Assert.Equal(1, outputsExitCode);
+        Assert.Contains("[Dead Rule] Member 'Legacy' is written by Rule 'Score' in RuleSet 'LoanDecision' but never read.", outputsOutput);
+        Assert.DoesNotContain("'Approved'", outputsOutput);
+    }
+}
@@ -0,0 +1,58 @@
+using System.Runtime.Loader;
+using Microsoft.Extensions.Logging.Abstractions;
+using Winterflood.RuleEngine.Compiler.Compiler;
+using Winterflood.RuleEngine.Compiler.Configuration.Models;
+using Winterflood.RuleEngine.UnitTests.Models;
+using Xunit;
+using Assert = Xunit.Assert;
+
+namespace Winterflood.RuleEngine.UnitTests;
+
+public class CompilationCacheTests
+{
+    private static RuleEngineConfiguration CreateConfiguration(string typeName)
+        => TestConfigurations.CreateConfiguration().WithTypes(TestConfigurations.CreateDataModel(typeName));
+
+    [Fact]
+    public void GetOrCompile_IdenticalConfiguration_ReturnsCachedCompilation()
+    {
+        var cache = new CompilationCache();
+
+        var first = cache.GetOrCompile(CreateConfiguration("Order"), NullLoggerFactory.Instance);
+        var second = cache.GetOrCompile(CreateConfiguration("Order"), NullLoggerFactory.Instance);
+
+        Assert.True(first.Success);
+        Assert.Same(first, second);
+        Assert.Equal(1, cache.Count);
+    }
+
+    [Fact]
+    public void GetOrCompile_CapacityExceeded_EvictsLeastRecentlyUsed()
+    {
+        var cache = new CompilationCache(capacity: 1);
+
+        var order = cache.GetOrCompile(CreateConfiguration("Order"), NullLoggerFactory.Instance);
+        cache.GetOrCompile(CreateConfiguration("Customer"), NullLoggerFactory.Instance);
+        var recompiled = cache.GetOrCompile(CreateConfiguration("Order"), NullLoggerFactory.Instance);
+
+        Assert.NotSame(order, recompiled);
+        Assert.Equal(1, cache.Count);
+    }
+
+    [Fact]
+    public void GetOrCompile_EvictedCompilation_IsNotUnloaded()
+    {
+        var cache = new CompilationCache(capacity: 1);
+        var order = cache.GetOrCompile(CreateConfiguration("Order"), NullLoggerFactory.Instance);
+        var loadContext = AssemblyLoadContext.GetLoadContext(order.CompiledAssembly!)!;
+        var unloaded = false;
+        loadContext.Unloading += _ => unloaded = true;
+
+        cache.GetOrCompile(CreateConfiguration("Customer"), NullLoggerFactory.Instance);
+        cache.Clear();
+
+        // Callers may still be executing the rulesets of an evicted compilation
+        Assert.Equal(0, cache.Count);
+        Assert.False(unloaded);
+    }
+}
@@ -0,0 +1,82 @@
+using Winterflood.RuleEngine.Compiler.Configuration;
+using Winterflood.RuleEngine.Compiler.Configuration.Models;
+using Winterflood.RuleEngine.Engine;
+using Winterflood.RuleEngine.UnitTests.Models;
+using Xunit;
+using Assert = Xunit.Assert;
+
+namespace Winterflood.RuleEngine.UnitTests;
+
+public class ConfigurationGraphExporterTests
+{
+    private static RuleEngineConfiguration CreateConfiguration()
+        => TestConfigurations.CreateConfiguration(
+            TestConfigurations.CreateRuleSet("CustomerEvaluation", TestConfigurations.CreateRule("EvaluateSpend")),
+            TestConfigurations.CreateRuleSet(
+                "OrderEvaluation",
+                TestConfigurations.CreateNestedRule("Customer", "CustomerEvaluation"),
+                TestConfigurations.CreateNestedRule("Shipping", "ShippingEvaluation")));
+
+    [Fact]
+    public void ToDot_RendersRulesAndNestedRulesetEdges()
+    {
+        var dot = ConfigurationGraphExporter.ToDot(CreateConfiguration());
+
+        Assert.StartsWith("digraph RuleEngine {", dot);
+        Assert.Contains("\"CustomerEvaluation\" -> \"CustomerEvaluation.EvaluateSpend\" [label=\"1\"];", dot);
+        Assert.Contains("\"OrderEvaluation.Customer\" -> \"CustomerEvaluation\" [style=dashed];", dot);
+        Assert.Contains("\"ShippingEvaluation\" [shape=box, style=dashed", dot);
+    }
+
+    [Fact]
+    public void ToMermaid_RendersRulesAndNestedRulesetEdges()
+    {
+        var mermaid = ConfigurationGraphExporter.ToMermaid(CreateConfiguration());
+
+        Assert.StartsWith("flowchart LR", mermaid);
+        Assert.Contains("rs0 -->|1| rs0_r0", mermaid);
+        Assert.Contains("rs1_r0 -.-> rs0", mermaid);
+        Assert.Contains("rs1_r1 -.-> rs2", mermaid);
+        Assert.Contains("rs2[\"ShippingEvaluation<br/>(missing)\"]", mermaid);
+    }
+
+    [Fact]
+    public void ToDot_RendersErrorBranchAndOverrideEdges()
+    {
+        var configuration = TestConfigurations.CreateConfiguration(
+            TestConfigurations.CreateRuleSet("BaseEvaluation", TestConfigurations.CreateRule("EvaluateSpend")),
+            TestConfigurations.CreateRuleSet(
+                    "CustomerEvaluation",
+                    TestConfigurations.CreateRule("EvaluateSpendStrictly").Overriding("EvaluateSpend"),
+                    TestConfigurations.CreateRule("LookupCredit").WithErrorPolicy(RuleErrorAction.Branch, "ManualReview"))
+                .Including("BaseEvaluation"));
+
+        var dot = ConfigurationGraphExporter.ToDot(configuration);
+
+        Assert.Contains(
+            "\"CustomerEvaluation.EvaluateSpendStrictly\" -> \"BaseEvaluation.EvaluateSpend\" [style=dotted, label=\"overrides\"];",
+            dot);
+        Assert.Contains("\"CustomerEvaluation.LookupCredit\" -> \"ManualReview\" [style=dashed, color=red, label=\"on error\"];", dot);
+        Assert.Contains("\"ManualReview\" [shape=box, style=dashed", dot);
+    }
+
+    [Fact]
+    public void ToMermaid_DrawsEnvironmentVariantsAsSeparateNodes()
+    {
+        var configuration = TestConfigurations.CreateConfiguration(
+            TestConfigurations.CreateRuleSet("CustomerEvaluation", TestConfigurations.CreateRule("EvaluateSpend")),
+            TestConfigurations.CreateRuleSet("CustomerEvaluation", TestConfigurations.CreateRule("EvaluateSpendStrictly"))
+                .ForEnvironments("production"),
+            TestConfigurations.CreateRuleSet("OrderEvaluation", TestConfigurations.CreateNestedRule("Customer", "CustomerEvaluation")));
+
+        var mermaid = ConfigurationGraphExporter.ToMermaid(configuration);
+        var dot = ConfigurationGraphExporter.ToDot(configuration);
+
+        Assert.Contains("rs1[\"CustomerEvaluation<br/>All<br/>[production]\"]", mermaid);
+        Assert.Contains("rs1 -->|1| rs1_r0", mermaid);
+        Assert.Contains("rs2_r0 -.-> rs0", mermaid);
+        Assert.Contains("rs2_r0 -.-> rs1", mermaid);
+        Assert.Contains("\"CustomerEvaluation@production\" -> \"CustomerEvaluation@production.EvaluateSpendStrictly\" [label=\"1\"];", dot);
+        Assert.Contains("\"OrderEvaluation.Customer\" -> \"CustomerEvaluation@production\" [style=dashed];", dot);
+    }
+}
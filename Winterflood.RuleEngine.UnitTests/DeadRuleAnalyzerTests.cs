@@ -0,0 +1,177 @@
+using Winterflood.RuleEngine.Compiler.Configuration;
+using Winterflood.RuleEngine.Compiler.Configuration.Models;
+using Winterflood.RuleEngine.Engine;
+using Xunit;
+using Assert = Xunit.Assert;
+
+namespace Winterflood.RuleEngine.UnitTests;
+
+public class DeadRuleAnalyzerTests
+{
+    private static StandardRuleDefinition CreateRule(string name, string conditions, string onSuccess = "")
+        => new() { RuleName = name, Conditions = conditions, OnSuccess = onSuccess };
+
+    [Fact]
+    public void Analyze_ReportsRulesAfterUnconditionalRule()
+    {
+        var configuration = new RuleEngineConfiguration
+        {
+            RuleSets =
+            [
+                new RuleSetDefinition
+                {
+                    Name = "Routing",
+                    DataType = "Order",
+                    ExecutionMode = RuleExecutionMode.StopOnFirstSuccess,
+                    Rules = [CreateRule("Express", "data.IsExpress"), CreateRule("Default", "true"), CreateRule("Fallback", "data.IsLate")]
+                }
+            ]
+        };
+
+        var findings = DeadRuleAnalyzer.Analyze(configuration, ["Routing"]);
+
+        Assert.Equal(
+            new[] { "Rule 'Fallback' in RuleSet 'Routing' is unreachable after unconditional Rule 'Default'." },
+            findings);
+    }
+
+    [Fact]
+    public void Analyze_ReportsMissingAndUnreachableRuleSets()
+    {
+        var configuration = new RuleEngineConfiguration
+        {
+            RuleSets =
+            [
+                new RuleSetDefinition
+                {
+                    Name = "Main",
+                    DataType = "Order",
+                    Rules =
+                    [
+                        new NestedRuleDefinition { RuleName = "Shipping", RulesetName = "ShippingRules" },
+                        new NestedRuleDefinition { RuleName = "Legacy", RulesetName = "Removed" }
+                    ]
+                },
+                new RuleSetDefinition { Name = "ShippingRules", DataType = "Order" },
+                new RuleSetDefinition { Name = "Unused", DataType = "Order" }
+            ]
+        };
+
+        var findings = DeadRuleAnalyzer.Analyze(configuration, ["Main"]);
+
+        Assert.Equal(
+            new[]
+            {
+                "Rule 'Legacy' in RuleSet 'Main' references undefined RuleSet 'Removed'.",
+                "RuleSet 'Unused' is never referenced and is not an entry point."
+            },
+            findings);
+    }
+
+    [Fact]
+    public void Analyze_ReportsUnresolvableIncludesOnce()
+    {
+        var configuration = new RuleEngineConfiguration
+        {
+            RuleSets =
+            [
+                new RuleSetDefinition { Name = "Main", DataType = "Order", Includes = ["Removed"] },
+                new RuleSetDefinition { Name = "Pricing", DataType = "Order", Includes = ["CustomerChecks"] },
+                new RuleSetDefinition { Name = "CustomerChecks", DataType = "Customer" }
+            ]
+        };
+
+        var findings = DeadRuleAnalyzer.Analyze(configuration, []);
+
+        Assert.Equal(
+            new[]
+            {
+                "RuleSet 'Main' includes unknown RuleSet 'Removed'.",
+                "RuleSet 'Pricing' (Order) cannot include RuleSet 'CustomerChecks' (Customer)."
+            },
+            findings);
+    }
+
+    [Fact]
+    public void Analyze_ReportsMembersWrittenButNeverRead()
+    {
+        var configuration = new RuleEngineConfiguration
+        {
+            RuleSets =
+            [
+                new RuleSetDefinition
+                {
+                    Name = "Pricing",
+                    DataType = "Order",
+                    Rules =
+                    [
+                        CreateRule("Flag", "data.Total > 100", "data.Customer.IsHighValue = true; data.Legacy = 1"),
+                        CreateRule("Discount", "data.Customer.IsHighValue", "data.Discount = 10")
+                    ]
+                }
+            ]
+        };
+
+        var findings = DeadRuleAnalyzer.Analyze(configuration, [], ["Discount"]);
+        var withoutOutputs = DeadRuleAnalyzer.Analyze(configuration, []);
+
+        Assert.Equal(new[] { "Member 'Legacy' is written by Rule 'Flag' in RuleSet 'Pricing' but never read." }, findings);
+        Assert.Empty(withoutOutputs);
+    }
+
+    [Fact]
+    public void Analyze_BoundAndNestedRules_ReportsMembersFromRulesetData()
+    {
+        var configuration = new RuleEngineConfiguration
+        {
+            RuleSets =
+            [
+                new RuleSetDefinition
+                {
+                    Name = "Orders",
+                    DataType = "Order",
+                    Rules =
+                    [
+                        new NestedRuleDefinition
+                        {
+                            RuleName = "CustomerChecks",
+                            RulesetName = "CustomerChecks",
+                            Adapters = ["AsRule", "Bind"],
+                            Binding = new BindingAdapter
+                            {
+                                BindSourceType = "Order",
+                                BindTargetType = "Customer",
+                                BindFactory = "return sourceData.Customer",
+                                AfterExecute = "sourceData.Checked = targetData.IsHighValue;"
+                            }
+                        },
+                        new StandardRuleDefinition
+                        {
+                            RuleName = "Pensioner",
+                            Adapters = ["Bind"],
+                            Binding = new BindingAdapter
+                            {
+                                BindSourceType = "Order",
+                                BindTargetType = "Customer",
+                                BindFactory = "return sourceData.Customer"
+                            },
+                            Conditions = "data.Age > 65",
+                            OnSuccess = "data.Discount = 5"
+                        }
+                    ]
+                },
+                new RuleSetDefinition
+                {
+                    Name = "CustomerChecks",
+                    DataType = "Customer",
+                    Rules = [CreateRule("Adult", "data.Age >= 18", "data.IsHighValue = true; data.Segment = 1")]
+                }
+            ]
+        };
+
+        var findings = DeadRuleAnalyzer.Analyze(configuration, ["Orders"], ["IsHighValue"]);
+
+        // Members of the bound customer are read by the bindings, and the nested rules are not reported relative to it
+        Assert.Equal(new[] { "Member 'Checked' is written by Rule 'CustomerChecks' in RuleSet 'Orders' but never read." }, findings);
+    }
+}
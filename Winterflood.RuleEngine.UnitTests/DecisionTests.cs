@@ -0,0 +1,142 @@
+using System.Reflection;
+using Microsoft.Extensions.Logging.Abstractions;
+using Winterflood.RuleEngine.Compiler.Compiler;
+using Winterflood.RuleEngine.Compiler.Configuration;
+using Winterflood.RuleEngine.Compiler.Configuration.Models;
+using Winterflood.RuleEngine.Compiler.Runners;
+using Winterflood.RuleEngine.UnitTests.Models;
+using Xunit;
+using Assert = Xunit.Assert;
+
+namespace Winterflood.RuleEngine.UnitTests;
+
+public class DecisionTests
+{
+    private static RuleEngineConfiguration CreateConfiguration()
+        => TestConfigurations.CreateConfiguration(
+                TestConfigurations.CreateRuleSet(
+                        "LoanDecision",
+                        TestConfigurations.CreateRule("AmountWithinLimit", "data.Amount <= 1000"))
+                    .WithDataType("LoanApplication"))
+            .WithTypes(TestConfigurations.CreateDataModel("LoanApplication", ("Amount", "int")));
+
+    [Fact]
+    public void ExecuteDecision_RecordsOutcomeAndProvenance()
+    {
+        var configuration = CreateConfiguration();
+        var compilation = SyntaxTreeCompiler.Compile(configuration, NullLoggerFactory.Instance);
+
+        var decision = RuleRunner.ExecuteDecision(
+            NullLoggerFactory.Instance,
+            compilation.CompiledAssembly!,
+            configuration,
+            "LoanDecision",
+            """{ "Amount": 5000 }""");
+
+        Assert.False(decision.Result);
+        Assert.Equal(RuleDefinitionParser.ComputeHash(configuration, NullLoggerFactory.Instance), decision.ConfigurationHash);
+        Assert.Equal(64, decision.InputHash.Length);
+        Assert.True(decision.Context.HasChildContext("AmountWithinLimit"));
+        Assert.True(decision.CompletedAt >= decision.StartedAt);
+        Assert.Contains("\"ConfigurationHash\"", decision.ToJson());
+    }
+
+    [Fact]
+    public void ExecuteDecision_RecordsActorAndTimesFromTimeProvider()
+    {
+        var configuration = CreateConfiguration();
+        var now = new DateTimeOffset(2024, 1, 1, 12, 0, 0, TimeSpan.Zero);
+
+        var decision = RuleRunner.ExecuteDecision(
+            NullLoggerFactory.Instance,
+            configuration,
+            "LoanDecision",
+            """{ "Amount": 500 }""",
+            actor: "underwriting-service",
+            timeProvider: new FixedTimeProvider(now));
+
+        Assert.Equal("underwriting-service", decision.Actor);
+        Assert.Equal(now, decision.StartedAt);
+        Assert.Equal(now, decision.CompletedAt);
+        Assert.Contains("\"Actor\": \"underwriting-service\"", decision.ToJson());
+    }
+
+    [Fact]
+    public void ComputeHash_DiffersWhenRulesChange()
+    {
+        var original = CreateConfiguration();
+        var changed = CreateConfiguration();
+        ((StandardRuleDefinition)changed.RuleSets[0].Rules[0]).Conditions = "data.Amount <= 2000";
+
+        Assert.NotEqual(
+            RuleDefinitionParser.ComputeHash(original, NullLoggerFactory.Instance),
+            RuleDefinitionParser.ComputeHash(changed, NullLoggerFactory.Instance));
+    }
+
+    [Fact]
+    public void ComputeHash_IgnoresGeneratedNames()
+    {
+        static RuleEngineConfiguration CreateUnnamedConfiguration() => new()
+        {
+            Types = [new DataModelDefinition { Fields = [new FieldDefinition { Type = "int" }, new FieldDefinition()] }],
+            RuleSets = [new RuleSetDefinition { Name = "Checks", Rules = [new StandardRuleDefinition { Conditions = "true" }] }]
+        };
+
+        Assert.Equal(
+            RuleDefinitionParser.ComputeHash(CreateUnnamedConfiguration(), NullLoggerFactory.Instance),
+            RuleDefinitionParser.ComputeHash(CreateUnnamedConfiguration(), NullLoggerFactory.Instance));
+    }
+
+    [Fact]
+    public void ExecuteDecision_FromConfiguration_ReusesGivenCache()
+    {
+        var configuration = CreateConfiguration();
+        var cache = new CompilationCache();
+
+        var first = RuleRunner.ExecuteDecision(NullLoggerFactory.Instance, configuration, "LoanDecision", """{ "Amount": 500 }""", cache: cache);
+        var cached = cache.GetOrCompile(configuration, NullLoggerFactory.Instance);
+        var second = RuleRunner.ExecuteDecision(NullLoggerFactory.Instance, configuration, "LoanDecision", """{ "Amount": 500 }""", cache: cache);
+
+        Assert.True(first.Result);
+        Assert.True(second.Result);
+        Assert.Equal(1, cache.Count);
+        Assert.Same(cached, cache.GetOrCompile(configuration, NullLoggerFactory.Instance));
+    }
+
+    [Fact]
+    public void Compile_StampsConfigurationHashIntoAssembly()
+    {
+        var configuration = CreateConfiguration();
+        var compilation = SyntaxTreeCompiler.Compile(configuration, NullLoggerFactory.Instance);
+
+        Assert.Equal(
+            RuleDefinitionParser.ComputeHash(configuration, NullLoggerFactory.Instance),
+            SyntaxTreeCompiler.GetConfigurationHash(compilation.CompiledAssembly!));
+    }
+
+    [Fact]
+    public void ExecuteDecision_AssemblyCompiledFromOtherConfiguration_Throws()
+    {
+        var compilation = SyntaxTreeCompiler.Compile(CreateConfiguration(), NullLoggerFactory.Instance);
+        var changed = CreateConfiguration();
+        ((StandardRuleDefinition)changed.RuleSets[0].Rules[0]).Conditions = "data.Amount <= 2000";
+
+        Assert.Throws<InvalidOperationException>(() =>
+            RuleRunner.ExecuteDecision(
+                NullLoggerFactory.Instance,
+                compilation.CompiledAssembly!,
+                changed,
+                "LoanDecision",
+                """{ "Amount": 500 }"""));
+    }
+
+    [Fact]
+    public void ExecuteDecision_RecordsInformationalEngineVersion()
+    {
+        var decision = RuleRunner.ExecuteDecision(NullLoggerFactory.Instance, CreateConfiguration(), "LoanDecision", """{ "Amount": 500 }""");
+
+        var informationalVersion =
+            typeof(RuleRunner).Assembly.GetCustomAttribute<AssemblyInformationalVersionAttribute>()!.InformationalVersion;
+        Assert.Equal(informationalVersion, decision.EngineVersion);
+    }
+}
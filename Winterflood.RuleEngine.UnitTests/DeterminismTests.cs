@@ -0,0 +1,137 @@
+using System.Text.Json;
+using System.Text.Json.Nodes;
+using Microsoft.Extensions.Logging.Abstractions;
+using Winterflood.RuleEngine.Compiler.Configuration.Models;
+using Winterflood.RuleEngine.Compiler.Runners;
+using Winterflood.RuleEngine.Engine;
+using Winterflood.RuleEngine.Engine.Context;
+using Winterflood.RuleEngine.Engine.Data;
+using Winterflood.RuleEngine.Engine.Rule;
+using Winterflood.RuleEngine.Engine.RuleSet;
+using Xunit;
+using Assert = Xunit.Assert;
+
+namespace Winterflood.RuleEngine.UnitTests;
+
+public class DeterminismTests
+{
+    public class TestData : IRuleData
+    {
+        public int Score { get; set; }
+        public List<string> Flags { get; set; } = [];
+    }
+
+    private static IRuleSet<TestData> CreateRuleset(string name, int threshold)
+    {
+        var ruleset = new RuleSet<TestData>(name, RuleExecutionMode.All, NullLoggerFactory.Instance);
+        ruleset.AddRule(() => new Rule<TestData>($"{name}Check", (data, _) => data.Score >= threshold, (_, _) => { }));
+        return ruleset;
+    }
+
+    private static string EvaluateAndSerialize()
+    {
+        var ruleset = new RuleSet<TestData>("Root", RuleExecutionMode.All, NullLoggerFactory.Instance);
+        ruleset.AddRule(() => new Rule<TestData>("Score", (data, _) => data.Score = 3));
+        ruleset.AddRule(() => new Rule<TestData>("Flag", (data, _) => data.Flags.Add("Scored")));
+        ruleset.AddRule(() => CreateRuleset("Nested", 1).AsRule());
+        ruleset.AddRule(() =>
+            new[] { CreateRuleset("Fraud", 2), CreateRuleset("Sanctions", 3), CreateRuleset("Velocity", 4) }
+                .AsGroup("RiskChecks", RuleGroupMode.All, runInParallel: true));
+
+        var context = new RootContext();
+        ruleset.Evaluate(new TestData(), context);
+
+        return JsonSerializer.Serialize(context.GetTimeline());
+    }
+
+    [Fact]
+    public void Evaluate_IdenticalInput_ProducesByteIdenticalAuditTrail()
+    {
+        var first = EvaluateAndSerialize();
+
+        for (var i = 0; i < 10; i++)
+            Assert.Equal(first, EvaluateAndSerialize());
+    }
+
+    private static readonly RuleEngineConfiguration Configuration = new()
+    {
+        Types =
+        [
+            new DataModelDefinition
+            {
+                Name = "Application",
+                Fields =
+                [
+                    new FieldDefinition { Name = "Score", Type = "int" },
+                    new FieldDefinition { Name = "Tier", Type = "string" }
+                ]
+            }
+        ],
+        RuleSets =
+        [
+            new RuleSetDefinition
+            {
+                Name = "Onboarding",
+                DataType = "Application",
+                Rules =
+                [
+                    new StandardRuleDefinition { RuleName = "Scored", Conditions = "true", OnSuccess = "data.Score = 3" },
+                    new StandardRuleDefinition { RuleName = "Tiered", Conditions = "data.Score >= 3", OnSuccess = "data.Tier = \"Gold\"" },
+                    new NestedRuleDefinition { RuleName = "Limits", RulesetName = "Limits", Adapters = ["AsRule"] }
+                ]
+            },
+            new RuleSetDefinition
+            {
+                Name = "Limits",
+                DataType = "Application",
+                Rules = [new StandardRuleDefinition { RuleName = "ScoreLimit", Conditions = "data.Score < 10" }]
+            }
+        ]
+    };
+
+    private static string ExecuteDecisionAndSerialize()
+    {
+        var decision = RuleRunner.ExecuteDecision(NullLoggerFactory.Instance, Configuration, "Onboarding", """{ "Score": 0 }""");
+
+        // Only the timestamps may differ between identical decisions
+        var json = JsonNode.Parse(decision.ToJson())!.AsObject();
+        json.Remove(nameof(Decision.StartedAt));
+        json.Remove(nameof(Decision.CompletedAt));
+
+        return json.ToJsonString();
+    }
+
+    [Fact]
+    public void ExecuteDecision_IdenticalInput_ProducesByteIdenticalDecision()
+    {
+        var first = ExecuteDecisionAndSerialize();
+
+        for (var i = 0; i < 10; i++)
+            Assert.Equal(first, ExecuteDecisionAndSerialize());
+
+        var childContexts = JsonNode.Parse(first)!["Context"]!["ChildContexts"]!.AsObject();
+        Assert.Equal(new[] { "Scored", "Tiered", "Limits" }, childContexts.Select(entry => entry.Key));
+        Assert.Equal("Tier", childContexts["Tiered"]!["Changes"]![0]!["Path"]!.GetValue<string>());
+    }
+
+    [Fact]
+    public void Evaluate_IdenticalInput_RecordsIdenticalChanges()
+    {
+        var ruleset = new RuleSet<TestData>("Root", RuleExecutionMode.All, NullLoggerFactory.Instance);
+        ruleset.AddRule(() => new Rule<TestData>("Score", (data, _) => data.Score = 3));
+        ruleset.AddRule(() => new Rule<TestData>("Flag", (data, _) => data.Flags.Add("Scored")));
+
+        string Serialize()
+        {
+            var context = new RootContext();
+            ruleset.Evaluate(new TestData(), context);
+            return JsonSerializer.Serialize(context.GetTimeline().Select(ruleContext => ruleContext.Changes));
+        }
+
+        var first = Serialize();
+
+        Assert.Equal(first, Serialize());
+        Assert.Contains("\"Path\":\"Score\"", first);
+        Assert.Contains("\"Path\":\"Flags[0]\"", first);
+    }
+}
@@ -0,0 +1,272 @@
+using Microsoft.Extensions.Logging.Abstractions;
+using Winterflood.RuleEngine.Compiler.Compiler;
+using Winterflood.RuleEngine.Compiler.Configuration.Models;
+using Winterflood.RuleEngine.Engine;
+using Winterflood.RuleEngine.Engine.Context;
+using Winterflood.RuleEngine.Engine.Data;
+using Winterflood.RuleEngine.Engine.Rule;
+using Winterflood.RuleEngine.Engine.RuleSet;
+using Xunit;
+using Assert = Xunit.Assert;
+
+namespace Winterflood.RuleEngine.UnitTests;
+
+public class ErrorPolicyRuleAdapterTests
+{
+    public class TestData : IRuleData
+    {
+        public bool Approved { get; set; }
+    }
+
+    private static RuleSet<TestData> CreateRuleset(Func<IRule<TestData>, IRule<TestData>> applyPolicy, int failures = int.MaxValue)
+    {
+        var attempts = 0;
+        var lookup = new Rule<TestData>(
+            "CreditLookup",
+            (_, _) => ++attempts > failures ? true : throw new TimeoutException("Lookup timed out"),
+            (data, _) => data.Approved = true);
+
+        var ruleset = new RuleSet<TestData>("Credit", RuleExecutionMode.All, NullLoggerFactory.Instance);
+        ruleset.AddRule(() => applyPolicy(lookup));
+        return ruleset;
+    }
+
+    [Fact]
+    public void Evaluate_FailAction_RecordsErrorAndFailsRule()
+    {
+        // Arrange
+        var ruleset = CreateRuleset(rule => rule.OnError(RuleErrorAction.Fail));
+        var context = new RootContext();
+
+        // Act
+        var result = ruleset.Evaluate(new TestData(), context);
+
+        // Assert
+        var ruleContext = context.GetChildContext<RuleContext>("CreditLookup");
+        Assert.False(result);
+        Assert.Equal("Lookup timed out", ruleContext!.Error);
+    }
+
+    [Fact]
+    public void Evaluate_SkipAction_RecordsRuleAsSkipped()
+    {
+        // Arrange
+        var ruleset = CreateRuleset(rule => rule.OnError(RuleErrorAction.Skip));
+        var context = new RootContext();
+
+        // Act
+        var result = ruleset.Evaluate(new TestData(), context);
+
+        // Assert
+        var ruleContext = context.GetChildContext<RuleContext>("CreditLookup");
+        Assert.True(result);
+        Assert.True(ruleContext!.Skipped);
+    }
+
+    [Fact]
+    public void Evaluate_RetryAction_SucceedsAfterTransientFailures()
+    {
+        // Arrange
+        var ruleset = CreateRuleset(rule => rule.OnError(RuleErrorAction.Retry, retryCount: 2), failures: 2);
+        var data = new TestData();
+
+        // Act
+        var result = ruleset.Evaluate(data, new RootContext());
+
+        // Assert
+        Assert.True(result);
+        Assert.True(data.Approved);
+    }
+
+    [Fact]
+    public void Evaluate_RetryAction_PropagatesErrorWhenRetriesAreExhausted()
+    {
+        // Arrange
+        var ruleset = CreateRuleset(rule => rule.OnError(RuleErrorAction.Retry, retryCount: 1), failures: 2);
+
+        // Act & Assert
+        Assert.Throws<TimeoutException>(() => ruleset.Evaluate(new TestData(), new RootContext()));
+    }
+
+    [Fact]
+    public void Evaluate_BranchAction_EvaluatesBranchRulesetInPlaceOfRule()
+    {
+        // Arrange
+        var manualReview = new RuleSet<TestData>("ManualReview", RuleExecutionMode.All, NullLoggerFactory.Instance);
+        manualReview.AddRule(() => new Rule<TestData>("FlagForReview", (_, _) => false, (_, _) => { }));
+        var ruleset = CreateRuleset(rule => rule.OnError(RuleErrorAction.Branch, branch: manualReview));
+        var context = new RootContext();
+
+        // Act
+        var result = ruleset.Evaluate(new TestData(), context);
+
+        // Assert
+        var ruleContext = context.GetChildContext<RuleContext>("CreditLookup")!;
+        Assert.False(result);
+        Assert.False(ruleContext.Skipped);
+        Assert.False(ruleContext.Result);
+        Assert.True(context.HasChildContext("CreditLookup[Branch]"));
+    }
+
+    [Fact]
+    public void Evaluate_BranchAction_UsesBranchResultAsRuleResult()
+    {
+        // Arrange
+        var manualReview = new RuleSet<TestData>("ManualReview", RuleExecutionMode.All, NullLoggerFactory.Instance);
+        manualReview.AddRule(() => new Rule<TestData>("ApproveManually", (data, _) => data.Approved = true));
+        var ruleset = CreateRuleset(rule => rule.OnError(RuleErrorAction.Branch, branch: manualReview));
+        var data = new TestData();
+        var context = new RootContext();
+
+        // Act
+        var result = ruleset.Evaluate(data, context);
+
+        // Assert
+        Assert.True(result);
+        Assert.True(context.GetChildContext<RuleContext>("CreditLookup")!.Result);
+        Assert.True(data.Approved);
+    }
+
+    [Fact]
+    public void Evaluate_RetryAction_ClearsErrorWhenRetrySucceeds()
+    {
+        // Arrange
+        var ruleset = CreateRuleset(rule => rule.OnError(RuleErrorAction.Retry, retryCount: 2), failures: 1);
+        var context = new RootContext();
+
+        // Act
+        ruleset.Evaluate(new TestData(), context);
+
+        // Assert
+        Assert.Null(context.GetChildContext<RuleContext>("CreditLookup")!.Error);
+    }
+
+    [Fact]
+    public void Evaluate_SkipAction_HandlesErrorRaisedBySuccessLogic()
+    {
+        // Arrange
+        var rule = new Rule<TestData>("Notify", (_, _) => true, (_, _) => throw new InvalidOperationException("Notification failed"));
+        var ruleset = new RuleSet<TestData>("Credit", RuleExecutionMode.All, NullLoggerFactory.Instance);
+        ruleset.AddRule(() => rule.OnError(RuleErrorAction.Skip));
+        var context = new RootContext();
+
+        // Act
+        var result = ruleset.Evaluate(new TestData(), context);
+
+        // Assert
+        var ruleContext = context.GetChildContext<RuleContext>("Notify")!;
+        Assert.True(result);
+        Assert.True(ruleContext.Skipped);
+        Assert.Equal("Notification failed", ruleContext.Error);
+    }
+
+    [Fact]
+    public void Evaluate_FailAction_FailsRuleWhoseSuccessLogicRaisesError()
+    {
+        // Arrange
+        var rule = new Rule<TestData>("Notify", (_, _) => true, (_, _) => throw new InvalidOperationException("Notification failed"));
+        var ruleset = new RuleSet<TestData>("Credit", RuleExecutionMode.StopOnFirstSuccess, NullLoggerFactory.Instance);
+        ruleset.AddRule(() => rule.OnError(RuleErrorAction.Fail));
+        var context = new RootContext();
+
+        // Act
+        var result = ruleset.Evaluate(new TestData(), context);
+
+        // Assert
+        Assert.False(result);
+        Assert.False(context.GetChildContext<RuleContext>("Notify")!.Result);
+    }
+
+    [Fact]
+    public void Evaluate_RetryAction_DoesNotRetrySuccessLogic()
+    {
+        // Arrange
+        var notifications = 0;
+        var rule = new Rule<TestData>("Notify", (_, _) => true, (_, _) =>
+        {
+            notifications++;
+            throw new InvalidOperationException("Notification failed");
+        });
+        var ruleset = new RuleSet<TestData>("Credit", RuleExecutionMode.All, NullLoggerFactory.Instance);
+        ruleset.AddRule(() => rule.OnError(RuleErrorAction.Retry, retryCount: 2));
+
+        // Act & Assert
+        Assert.Throws<InvalidOperationException>(() => ruleset.Evaluate(new TestData(), new RootContext()));
+        Assert.Equal(1, notifications);
+    }
+
+    [Fact]
+    public void Success_SkipActionOutsideRuleset_DoesNotRunSuccessLogic()
+    {
+        // Arrange
+        var lookup = new Rule<TestData>("CreditLookup", (_, _) => throw new TimeoutException("Lookup timed out"), (data, _) => data.Approved = true);
+        var adapter = lookup.OnError(RuleErrorAction.Skip);
+        var data = new TestData();
+        var context = new RootContext();
+
+        // Act
+        var passed = adapter.Evaluate(data, context);
+        adapter.Success(data, context);
+
+        // Assert
+        Assert.True(passed);
+        Assert.False(data.Approved);
+        Assert.True(context.GetChildContext<RuleContext>("CreditLookup")!.Skipped);
+    }
+
+    [Fact]
+    public void Success_BranchActionReevaluatedWithoutError_RunsSuccessLogicOfRule()
+    {
+        // Arrange
+        var attempts = 0;
+        var lookup = new Rule<TestData>(
+            "CreditLookup",
+            (_, _) => ++attempts > 1 ? true : throw new TimeoutException("Lookup timed out"),
+            (data, _) => data.Approved = true);
+        var manualReview = new RuleSet<TestData>("ManualReview", RuleExecutionMode.All, NullLoggerFactory.Instance);
+        manualReview.AddRule(() => new Rule<TestData>("FlagForReview", (_, _) => false, (_, _) => { }));
+        var adapter = lookup.OnError(RuleErrorAction.Branch, branch: manualReview);
+        var context = new RootContext();
+        adapter.Evaluate(new TestData(), context);
+        var data = new TestData();
+
+        // Act
+        var passed = adapter.Evaluate(data, context);
+        adapter.Success(data, context);
+
+        // Assert
+        Assert.True(passed);
+        Assert.True(data.Approved);
+    }
+
+    [Fact]
+    public void Compile_RetryPolicyWithoutRetries_Throws()
+    {
+        // Arrange
+        var configuration = new RuleEngineConfiguration
+        {
+            Types = [new DataModelDefinition { Name = "CreditApplication" }],
+            RuleSets =
+            [
+                new RuleSetDefinition
+                {
+                    Name = "Credit",
+                    DataType = "CreditApplication",
+                    Rules =
+                    [
+                        new StandardRuleDefinition
+                        {
+                            RuleName = "CreditLookup",
+                            Conditions = "true",
+                            ErrorPolicy = new ErrorPolicyDefinition { Action = RuleErrorAction.Retry }
+                        }
+                    ]
+                }
+            ]
+        };
+
+        // Act & Assert
+        var exception = Assert.Throws<InvalidOperationException>(() => SyntaxTreeCompiler.Compile(configuration, NullLoggerFactory.Instance));
+        Assert.Contains("RetryCount", exception.Message);
+    }
+}
@@ -0,0 +1,124 @@
+using Microsoft.Extensions.Logging.Abstractions;
+using Winterflood.RuleEngine.Compiler.Configuration.Models;
+using Winterflood.RuleEngine.Compiler.Configuration.Repository;
+using Winterflood.RuleEngine.Engine;
+using Xunit;
+using Assert = Xunit.Assert;
+
+namespace Winterflood.RuleEngine.UnitTests;
+
+public class FileSystemRuleSetRepositoryTests : IDisposable
+{
+    private readonly string _directory = Path.Combine(Path.GetTempPath(), $"rulesets_{Guid.NewGuid():N}");
+
+    public void Dispose()
+    {
+        if (Directory.Exists(_directory))
+            Directory.Delete(_directory, true);
+    }
+
+    [Fact]
+    public void SaveRuleSet_ThenLoadRuleSet_RoundTripsRuleDefinitions()
+    {
+        // Arrange
+        var repository = new FileSystemRuleSetRepository(_directory, NullLoggerFactory.Instance);
+        var ruleSet = new RuleSetDefinition
+        {
+            Name = "OrderEvaluation",
+            DataType = "Order",
+            ExecutionMode = RuleExecutionMode.StopOnFirstFailure,
+            Rules =
+            [
+                new StandardRuleDefinition
+                {
+                    RuleName = "EvaluateSpend",
+                    Conditions = "data.TotalSpend > 1000",
+                    OnSuccess = "data.IsHighValue = true"
+                },
+                new NestedRuleDefinition
+                {
+                    RuleName = "Customer",
+                    RulesetName = "CustomerEvaluation",
+                    Adapters = ["AsRule"]
+                }
+            ]
+        };
+
+        // Act
+        repository.SaveRuleSet(ruleSet);
+        var loaded = repository.LoadRuleSet("OrderEvaluation");
+
+        // Assert
+        Assert.NotNull(loaded);
+        Assert.Equal(RuleExecutionMode.StopOnFirstFailure, loaded!.ExecutionMode);
+        Assert.Equal(2, loaded.Rules.Count);
+        var standard = Assert.IsType<StandardRuleDefinition>(loaded.Rules[0]);
+        Assert.Equal("data.TotalSpend > 1000", standard.Conditions);
+        var nested = Assert.IsType<NestedRuleDefinition>(loaded.Rules[1]);
+        Assert.Equal("CustomerEvaluation", nested.RulesetName);
+    }
+
+    [Fact]
+    public void ListRuleSets_ReturnsSavedNamesInOrder()
+    {
+        // Arrange
+        var repository = new FileSystemRuleSetRepository(_directory, NullLoggerFactory.Instance);
+        repository.SaveRuleSet(new RuleSetDefinition { Name = "B" });
+        repository.SaveRuleSet(new RuleSetDefinition { Name = "A" });
+
+        // Act
+        var names = repository.ListRuleSets();
+
+        // Assert
+        Assert.Equal(new[] { "A", "B" }, names);
+    }
+
+    [Fact]
+    public void LoadRuleSet_WhenMissing_ReturnsNull()
+    {
+        var repository = new FileSystemRuleSetRepository(_directory, NullLoggerFactory.Instance);
+
+        Assert.Null(repository.LoadRuleSet("Missing"));
+    }
+
+    [Fact]
+    public void LoadRuleSet_WhenFileDefinesOtherName_ReturnsNull()
+    {
+        var repository = new FileSystemRuleSetRepository(_directory, NullLoggerFactory.Instance);
+        repository.SaveRuleSet(new RuleSetDefinition { Name = "Original" });
+        File.Copy(Path.Combine(_directory, "Original.json"), Path.Combine(_directory, "Copy.json"));
+
+        Assert.NotNull(repository.LoadRuleSet("Original"));
+        Assert.Null(repository.LoadRuleSet("Copy"));
+    }
+
+    [Fact]
+    public void LoadRuleSet_WithPathTraversal_ThrowsArgumentException()
+    {
+        var repository = new FileSystemRuleSetRepository(_directory, NullLoggerFactory.Instance);
+
+        Assert.Throws<ArgumentException>(() => repository.LoadRuleSet("../outside"));
+    }
+
+    [Fact]
+    public void Watch_WhenRuleSetSaved_NotifiesRuleSetName()
+    {
+        // Arrange
+        var repository = new FileSystemRuleSetRepository(_directory, NullLoggerFactory.Instance);
+        using var notified = new ManualResetEventSlim();
+        string? changedName = null;
+
+        using var watcher = repository.Watch(name =>
+        {
+            changedName = name;
+            notified.Set();
+        });
+
+        // Act
+        repository.SaveRuleSet(new RuleSetDefinition { Name = "OrderEvaluation" });
+
+        // Assert
+        Assert.True(notified.Wait(TimeSpan.FromSeconds(5)));
+        Assert.Equal("OrderEvaluation", changedName);
+    }
+}
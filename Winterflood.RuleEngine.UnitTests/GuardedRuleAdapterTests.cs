@@ -0,0 +1,103 @@
+using Microsoft.Extensions.Logging.Abstractions;
+using Winterflood.RuleEngine.Engine;
+using Winterflood.RuleEngine.Engine.Context;
+using Winterflood.RuleEngine.Engine.Data;
+using Winterflood.RuleEngine.Engine.Rule;
+using Winterflood.RuleEngine.Engine.RuleSet;
+using Xunit;
+using Assert = Xunit.Assert;
+
+namespace Winterflood.RuleEngine.UnitTests;
+
+public class GuardedRuleAdapterTests
+{
+    public class TestData : IRuleData
+    {
+        public bool IsMember { get; set; }
+        public decimal Discount { get; set; }
+    }
+
+    private static RuleSet<TestData> CreateRuleset()
+    {
+        var ruleset = new RuleSet<TestData>("Pricing", RuleExecutionMode.All, NullLoggerFactory.Instance);
+        ruleset.AddRule(() =>
+            new Rule<TestData>("MemberDiscount", (_, _) => false, (data, _) => data.Discount = 10)
+                .WithGuard((data, _) => data.IsMember));
+        return ruleset;
+    }
+
+    [Fact]
+    public void Evaluate_GuardNotMet_SkipsRuleAndRecordsItAsSkipped()
+    {
+        // Arrange
+        var ruleset = CreateRuleset();
+        var context = new RootContext();
+
+        // Act
+        var result = ruleset.Evaluate(new TestData { IsMember = false }, context);
+
+        // Assert
+        var ruleContext = context.GetChildContext<RuleContext>("MemberDiscount");
+        Assert.True(result);
+        Assert.NotNull(ruleContext);
+        Assert.True(ruleContext.Skipped);
+    }
+
+    [Fact]
+    public void Evaluate_GuardMet_EvaluatesRule()
+    {
+        // Arrange
+        var ruleset = CreateRuleset();
+        var context = new RootContext();
+
+        // Act
+        var result = ruleset.Evaluate(new TestData { IsMember = true }, context);
+
+        // Assert
+        var ruleContext = context.GetChildContext<RuleContext>("MemberDiscount");
+        Assert.False(result);
+        Assert.NotNull(ruleContext);
+        Assert.False(ruleContext.Skipped);
+    }
+
+    [Fact]
+    public void Evaluate_GuardedRuleWrappedInErrorPolicy_SkipsRule()
+    {
+        // Arrange
+        var ruleset = new RuleSet<TestData>("Pricing", RuleExecutionMode.All, NullLoggerFactory.Instance);
+        ruleset.AddRule(() =>
+            new Rule<TestData>("MemberDiscount", (_, _) => false, (data, _) => data.Discount = 10)
+                .WithGuard((data, _) => data.IsMember)
+                .OnError(RuleErrorAction.Fail));
+        var context = new RootContext();
+
+        // Act
+        var result = ruleset.Evaluate(new TestData { IsMember = false }, context);
+
+        // Assert
+        var ruleContext = context.GetChildContext<RuleContext>("MemberDiscount");
+        Assert.True(result);
+        Assert.NotNull(ruleContext);
+        Assert.True(ruleContext.Skipped);
+    }
+
+    [Fact]
+    public void Evaluate_GuardedRuleWrappedInCollection_SkipsItemsWhoseGuardIsNotMet()
+    {
+        // Arrange
+        var rule =
+            new Rule<TestData>("MemberDiscount", (_, _) => false, (_, _) => { })
+                .WithGuard((data, _) => data.IsMember)
+                .ForCollection();
+        var context = new RootContext();
+
+        // Act
+        var result = rule.Evaluate([new TestData { IsMember = false }, new TestData { IsMember = true }], context);
+
+        // Assert
+        var skippedItem = context.GetChildContext<RootContext>("MemberDiscount[0]")!.GetChildContext<RuleContext>("MemberDiscount");
+        Assert.False(result);
+        Assert.NotNull(skippedItem);
+        Assert.True(skippedItem.Skipped);
+    }
+}
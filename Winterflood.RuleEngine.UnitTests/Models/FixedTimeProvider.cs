@@ -0,0 +1,9 @@
+namespace Winterflood.RuleEngine.UnitTests.Models;
+
+/// <summary>
+/// A clock that always reports the same time.
+/// </summary>
+public sealed class FixedTimeProvider(DateTimeOffset now) : TimeProvider
+{
+    public override DateTimeOffset GetUtcNow() => now;
+}
@@ -0,0 +1,86 @@
+using Winterflood.RuleEngine.Compiler.Configuration.Models;
+using Winterflood.RuleEngine.Engine;
+
+namespace Winterflood.RuleEngine.UnitTests.Models;
+
+/// <summary>
+/// Builds the configuration definitions shared by the compiler, runner and configuration tests.
+/// </summary>
+/// <remarks>
+/// Rulesets operate on <see cref="DataType"/> unless another type is given with <see cref="WithDataType"/>.
+/// Configurations that are only analyzed need no data models; configurations that are compiled add them with <see cref="WithTypes"/>.
+/// </remarks>
+public static class TestConfigurations
+{
+    /// <summary>
+    /// The data type rulesets operate on by default.
+    /// </summary>
+    public const string DataType = "Order";
+
+    public static RuleEngineConfiguration CreateConfiguration(params RuleSetDefinition[] ruleSets)
+        => new() { RuleSets = [..ruleSets] };
+
+    public static RuleEngineConfiguration WithTypes(this RuleEngineConfiguration configuration, params DataModelDefinition[] types)
+    {
+        configuration.Types.AddRange(types);
+        return configuration;
+    }
+
+    public static RuleEngineConfiguration WithEnvironments(this RuleEngineConfiguration configuration, params string[] environments)
+    {
+        configuration.Environments.AddRange(environments);
+        return configuration;
+    }
+
+    public static DataModelDefinition CreateDataModel(string name, params (string Name, string Type)[] fields) => new()
+    {
+        Name = name,
+        Fields = fields.Select(field => new FieldDefinition { Name = field.Name, Type = field.Type }).ToList()
+    };
+
+    public static RuleSetDefinition CreateRuleSet(string name, params RuleDefinition[] rules)
+        => new() { Name = name, DataType = DataType, Rules = [..rules] };
+
+    public static RuleSetDefinition WithDataType(this RuleSetDefinition ruleSet, string dataType)
+    {
+        ruleSet.DataType = dataType;
+        return ruleSet;
+    }
+
+    public static RuleSetDefinition Including(this RuleSetDefinition ruleSet, params string[] includes)
+    {
+        ruleSet.Includes.AddRange(includes);
+        return ruleSet;
+    }
+
+    public static RuleSetDefinition ForEnvironments(this RuleSetDefinition ruleSet, params string[] environments)
+    {
+        ruleSet.Environments.AddRange(environments);
+        return ruleSet;
+    }
+
+    public static StandardRuleDefinition CreateRule(string name, string conditions = "true", string onSuccess = "")
+        => new() { RuleName = name, Conditions = conditions, OnSuccess = onSuccess };
+
+    public static NestedRuleDefinition CreateNestedRule(string name, string ruleSetName)
+        => new() { RuleName = name, RulesetName = ruleSetName };
+
+    public static TRule Overriding<TRule>(this TRule rule, string overrides) where TRule : RuleDefinition
+    {
+        rule.Overrides = overrides;
+        return rule;
+    }
+
+    public static TRule WithAdapters<TRule>(this TRule rule, params string[] adapters) where TRule : RuleDefinition
+    {
+        rule.Adapters.AddRange(adapters);
+        return rule;
+    }
+
+    public static TRule WithErrorPolicy<TRule>(this TRule rule, RuleErrorAction action, string? ruleSetName = null)
+        where TRule : RuleDefinition
+    {
+        rule.ErrorPolicy = new ErrorPolicyDefinition { Action = action, RuleSetName = ruleSetName };
+        return rule;
+    }
+}
@@ -0,0 +1,132 @@
+using Microsoft.Extensions.Logging.Abstractions;
+using Moq;
+using Winterflood.RuleEngine.Compiler.Configuration.Models;
+using Winterflood.RuleEngine.Compiler.Configuration.Repository;
+using Winterflood.RuleEngine.Compiler.Runners;
+using Winterflood.RuleEngine.Engine;
+using Xunit;
+using Assert = Xunit.Assert;
+
+namespace Winterflood.RuleEngine.UnitTests;
+
+public class RepositoryRuleRunnerTests
+{
+    private static readonly DataModelDefinition[] Types =
+    [
+        new DataModelDefinition
+        {
+            Name = "LoanApplication",
+            Fields = [new FieldDefinition { Name = "Amount", Type = "int" }]
+        }
+    ];
+
+    private static RuleSetDefinition CreateLimitRuleSet(int limit) => new()
+    {
+        Name = "Limits",
+        DataType = "LoanApplication",
+        Rules = [new StandardRuleDefinition { RuleName = "AmountWithinLimit", Conditions = $"data.Amount <= {limit}" }]
+    };
+
+    private static RuleSetDefinition CreateMainRuleSet() => new()
+    {
+        Name = "LoanDecision",
+        DataType = "LoanApplication",
+        Rules = [new NestedRuleDefinition { RuleName = "CheckLimits", RulesetName = "Limits", Adapters = ["AsRule"] }]
+    };
+
+    private static Mock<IRuleSetRepository> CreateRepository(Func<RuleSetDefinition> limits, Action<Action<string>> onWatch)
+    {
+        var repository = new Mock<IRuleSetRepository>();
+        repository.Setup(r => r.LoadRuleSet("LoanDecision")).Returns(() => CreateMainRuleSet());
+        repository.Setup(r => r.LoadRuleSet("Limits")).Returns(() => limits());
+        repository
+            .Setup(r => r.Watch(It.IsAny<Action<string>>()))
+            .Returns((Action<string> callback) =>
+            {
+                onWatch(callback);
+                return Mock.Of<IDisposable>();
+            });
+        return repository;
+    }
+
+    [Fact]
+    public void Execute_LoadsRuleSetAndReferencesOnFirstUse()
+    {
+        // Arrange
+        var repository = CreateRepository(() => CreateLimitRuleSet(1000), _ => { });
+        using var runner = new RepositoryRuleRunner(repository.Object, Types, NullLoggerFactory.Instance);
+
+        // Act
+        var loadedBeforeExecution = runner.IsLoaded("LoanDecision");
+        var result = runner.Execute("LoanDecision", """{ "Amount": 500 }""");
+
+        // Assert
+        Assert.False(loadedBeforeExecution);
+        Assert.True(result.Result);
+        Assert.True(runner.IsLoaded("LoanDecision"));
+        repository.Verify(r => r.LoadRuleSet("Limits"), Times.Once);
+    }
+
+    [Fact]
+    public void Execute_AfterReferencedRuleSetChanges_Recompiles()
+    {
+        // Arrange
+        var limit = 1000;
+        Action<string>? notifyChanged = null;
+        var repository = CreateRepository(() => CreateLimitRuleSet(limit), callback => notifyChanged = callback);
+        using var runner = new RepositoryRuleRunner(repository.Object, Types, NullLoggerFactory.Instance);
+
+        var before = runner.Execute("LoanDecision", """{ "Amount": 5000 }""");
+
+        // Act
+        limit = 10000;
+        notifyChanged!("Limits");
+        var after = runner.Execute("LoanDecision", """{ "Amount": 5000 }""");
+
+        // Assert
+        Assert.False(before.Result);
+        Assert.True(after.Result);
+        repository.Verify(r => r.LoadRuleSet("Limits"), Times.Exactly(2));
+    }
+
+    [Fact]
+    public void Execute_MissingReference_ReturnsNullResult()
+    {
+        var repository = CreateRepository(() => null!, _ => { });
+        using var runner = new RepositoryRuleRunner(repository.Object, Types, NullLoggerFactory.Instance);
+
+        var result = runner.Execute("LoanDecision", """{ "Amount": 500 }""");
+
+        Assert.Null(result.Result);
+        Assert.Equal("RuleSet 'LoanDecision' references RuleSet 'Limits' which is not in the repository.", result.Error);
+        Assert.False(runner.IsLoaded("LoanDecision"));
+    }
+
+    [Fact]
+    public void Execute_RuleSetNamedDifferently_ReturnsNullResultWithError()
+    {
+        var repository = CreateRepository(() => CreateLimitRuleSet(1000), _ => { });
+        repository.Setup(r => r.LoadRuleSet("Renamed")).Returns(() => CreateMainRuleSet());
+        using var runner = new RepositoryRuleRunner(repository.Object, Types, NullLoggerFactory.Instance);
+
+        var result = runner.Execute("Renamed", """{ "Amount": 500 }""");
+
+        Assert.Null(result.Result);
+        Assert.Equal("RuleSet 'Renamed' loaded from the repository is named 'LoanDecision'.", result.Error);
+    }
+
+    [Fact]
+    public void Execute_ConfigurationRejectedByCompiler_ReturnsNullResultWithError()
+    {
+        var limits = CreateLimitRuleSet(1000);
+        limits.Rules[0].ErrorPolicy = new ErrorPolicyDefinition { Action = RuleErrorAction.Retry };
+        var repository = CreateRepository(() => limits, _ => { });
+        using var runner = new RepositoryRuleRunner(repository.Object, Types, NullLoggerFactory.Instance);
+
+        var result = runner.Execute("Limits", """{ "Amount": 500 }""");
+
+        Assert.Null(result.Result);
+        Assert.Contains("RetryCount", result.Error);
+        Assert.False(runner.IsLoaded("Limits"));
+    }
+}
@@ -0,0 +1,70 @@
+using System.Text.Json;
+using Microsoft.Extensions.Logging.Abstractions;
+using Winterflood.RuleEngine.Engine;
+using Winterflood.RuleEngine.Engine.Context;
+using Winterflood.RuleEngine.Engine.Data;
+using Winterflood.RuleEngine.Engine.Rule;
+using Winterflood.RuleEngine.Engine.RuleSet;
+using Xunit;
+using Assert = Xunit.Assert;
+
+namespace Winterflood.RuleEngine.UnitTests;
+
+public class RootContextTests
+{
+    public class TestData : IRuleData
+    {
+        public decimal Rate { get; set; }
+    }
+
+    public class RateService
+    {
+        public decimal GetRate() => 1.25m;
+    }
+
+    [Fact]
+    public void GetData_ReturnsAttachedDataOrNull()
+    {
+        var service = new RateService();
+        var context = new RootContext().WithData(service);
+
+        Assert.Same(service, context.GetData<RateService>());
+        Assert.Null(context.GetData<string>());
+    }
+
+    [Fact]
+    public void GetData_IsAvailableWithinNestedRulesets()
+    {
+        var nested = new RuleSet<TestData>("Pricing", RuleExecutionMode.All, NullLoggerFactory.Instance);
+        nested.AddRule(() => new Rule<TestData>(
+            "ApplyRate",
+            (data, ctx) => data.Rate = ctx.GetData<RateService>()!.GetRate()));
+
+        var ruleset = new RuleSet<TestData>("Order", RuleExecutionMode.All, NullLoggerFactory.Instance);
+        ruleset.AddRule(() => nested.AsRule());
+
+        var data = new TestData();
+        ruleset.Evaluate(data, new RootContext().WithData(new RateService()));
+
+        Assert.Equal(1.25m, data.Rate);
+    }
+
+    [Fact]
+    public void ChildContexts_KeepInsertionOrder()
+    {
+        var context = new RootContext();
+        context.SetChildContext("First", 1);
+        context.SetChildContext("Second", 2);
+        context.SetChildContext("Third", 3);
+
+        // Updating keeps the position the context was first stored at
+        context.SetChildContext("First", 10);
+        context.SetChildContext("Fourth", 4);
+
+        Assert.Equal(new[] { "First", "Second", "Third", "Fourth" }, context.ChildContexts.Keys);
+        Assert.Equal(new object?[] { 10, 2, 3, 4 }, context.ChildContexts.Values);
+        Assert.Equal(
+            """{"ChildContexts":{"First":10,"Second":2,"Third":3,"Fourth":4}}""",
+            JsonSerializer.Serialize(context));
+    }
+}
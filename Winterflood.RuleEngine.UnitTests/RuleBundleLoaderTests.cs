@@ -0,0 +1,104 @@
+using System.Text;
+using Microsoft.Extensions.Logging.Abstractions;
+using Winterflood.RuleEngine.Compiler.Configuration.Bundle;
+using Winterflood.RuleEngine.Compiler.Configuration.Models;
+using Winterflood.RuleEngine.Compiler.Runners;
+using Winterflood.RuleEngine.UnitTests.Models;
+using Xunit;
+using Assert = Xunit.Assert;
+
+namespace Winterflood.RuleEngine.UnitTests;
+
+public class RuleBundleLoaderTests
+{
+    private static readonly HmacRuleBundleVerifier Verifier = new(Encoding.UTF8.GetBytes("approval-key"));
+
+    private static RuleEngineConfiguration CreateConfiguration()
+        => TestConfigurations.CreateConfiguration(
+                TestConfigurations.CreateRuleSet("OrderChecks", TestConfigurations.CreateRule("AgeCheck", "data.IsAdult")))
+            .WithTypes(TestConfigurations.CreateDataModel(TestConfigurations.DataType, ("IsAdult", "bool")));
+
+    [Fact]
+    public void LoadBundle_SignedBundle_IsVerified()
+    {
+        var json = RuleBundleLoader.CreateBundle(CreateConfiguration(), NullLoggerFactory.Instance, Verifier.Sign);
+
+        var verification = RuleBundleLoader.LoadBundle(json, Verifier, NullLoggerFactory.Instance, out var configuration);
+
+        Assert.Equal(RuleBundleVerification.Verified, verification);
+        Assert.Equal("OrderChecks", Assert.Single(configuration!.RuleSets).Name);
+        Assert.Equal(RuleBundleVerification.Verified, configuration.Bundle!.Verification);
+    }
+
+    [Fact]
+    public void LoadBundle_TamperedPropertyUnknownToModel_ReportsHashMismatch()
+    {
+        var configurationJson = """{ "ApprovedBy": "risk-committee", "RuleSets": [] }""";
+        var json = RuleBundleLoader.CreateBundle(configurationJson, Verifier.Sign)
+            .Replace("risk-committee", "someone-else");
+
+        var verification = RuleBundleLoader.LoadBundle(json, Verifier, NullLoggerFactory.Instance, out var configuration);
+
+        Assert.Equal(RuleBundleVerification.HashMismatch, verification);
+        Assert.Null(configuration);
+    }
+
+    [Fact]
+    public void ExecuteDecision_ConfigurationFromBundle_RecordsVerification()
+    {
+        var json = RuleBundleLoader.CreateBundle(CreateConfiguration(), NullLoggerFactory.Instance, Verifier.Sign);
+        RuleBundleLoader.LoadBundle(json, Verifier, NullLoggerFactory.Instance, out var configuration);
+
+        var decision = RuleRunner.ExecuteDecision(NullLoggerFactory.Instance, configuration!, "OrderChecks", """{ "IsAdult": true }""");
+
+        Assert.True(decision.Result);
+        Assert.Equal(RuleBundleVerification.Verified, decision.Bundle!.Verification);
+        Assert.Equal(configuration!.Bundle!.Hash, decision.Bundle.Hash);
+        Assert.Contains("\"Verified\"", decision.ToJson());
+    }
+
+    [Fact]
+    public void LoadBundle_TamperedConfiguration_ReportsHashMismatch()
+    {
+        var json = RuleBundleLoader.CreateBundle(CreateConfiguration(), NullLoggerFactory.Instance, Verifier.Sign)
+            .Replace("data.IsAdult", "data.IsMinor");
+
+        var verification = RuleBundleLoader.LoadBundle(json, Verifier, NullLoggerFactory.Instance, out var configuration);
+
+        Assert.Equal(RuleBundleVerification.HashMismatch, verification);
+        Assert.Null(configuration);
+    }
+
+    [Fact]
+    public void LoadBundle_UnsignedBundleWithVerifier_ReportsInvalidSignature()
+    {
+        var json = RuleBundleLoader.CreateBundle(CreateConfiguration(), NullLoggerFactory.Instance);
+
+        var verification = RuleBundleLoader.LoadBundle(json, Verifier, NullLoggerFactory.Instance, out var configuration);
+
+        Assert.Equal(RuleBundleVerification.InvalidSignature, verification);
+        Assert.Null(configuration);
+    }
+
+    [Fact]
+    public void LoadBundle_NullConfiguration_ReportsInvalid()
+    {
+        var json = """{ "Hash": "00", "Configuration": null }""";
+
+        var verification = RuleBundleLoader.LoadBundle(json, null, NullLoggerFactory.Instance, out var configuration);
+
+        Assert.Equal(RuleBundleVerification.Invalid, verification);
+        Assert.Null(configuration);
+    }
+
+    [Fact]
+    public void LoadBundle_EmptyConfiguration_ReportsInvalid()
+    {
+        var json = RuleBundleLoader.CreateBundle(string.Empty);
+
+        var verification = RuleBundleLoader.LoadBundle(json, null, NullLoggerFactory.Instance, out var configuration);
+
+        Assert.Equal(RuleBundleVerification.Invalid, verification);
+        Assert.Null(configuration);
+    }
+}
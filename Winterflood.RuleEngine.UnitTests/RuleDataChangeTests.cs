@@ -0,0 +1,63 @@
+using Microsoft.Extensions.Logging.Abstractions;
+using Winterflood.RuleEngine.Engine;
+using Winterflood.RuleEngine.Engine.Context;
+using Winterflood.RuleEngine.Engine.Data;
+using Winterflood.RuleEngine.Engine.Rule;
+using Winterflood.RuleEngine.Engine.RuleSet;
+using Xunit;
+using Assert = Xunit.Assert;
+
+namespace Winterflood.RuleEngine.UnitTests;
+
+public class RuleDataChangeTests
+{
+    public class Customer
+    {
+        public string Name { get; set; } = string.Empty;
+        public bool IsHighValue { get; set; }
+    }
+
+    public class Order : IRuleData
+    {
+        public Customer Customer { get; set; } = new();
+        public List<int> Quantities { get; set; } = [];
+        public decimal Discount { get; set; }
+    }
+
+    [Fact]
+    public void Compare_ReportsNestedAndListChangesByPath()
+    {
+        var before = new Order { Customer = new Customer { Name = "Alice" }, Quantities = [1, 2] };
+        var after = new Order { Customer = new Customer { Name = "Alice", IsHighValue = true }, Quantities = [1, 3, 4] };
+
+        var changes = RuleDataChange.Compare(before, after);
+
+        Assert.Equal(new[] { "Customer.IsHighValue", "Quantities[1]", "Quantities[2]" }, changes.Select(c => c.Path));
+        Assert.Null(changes[2].Before);
+        Assert.Equal(4, changes[2].After!.GetValue<int>());
+    }
+
+    [Fact]
+    public void Compare_IdenticalData_ReturnsNoChanges()
+    {
+        var changes = RuleDataChange.Compare(new Order { Discount = 5 }, new Order { Discount = 5 });
+
+        Assert.Empty(changes);
+    }
+
+    [Fact]
+    public void RuleSet_RecordsChangesOnTimelineInEvaluationOrder()
+    {
+        var ruleSet = new RuleSet<Order>("Pricing", RuleExecutionMode.All, NullLoggerFactory.Instance);
+        ruleSet.AddRule(() => new Rule<Order>("HighValue", (data, _) => data.Customer.IsHighValue = true));
+        ruleSet.AddRule(() => new Rule<Order>("Discount", (data, _) => data.Discount = 10));
+
+        var context = new RootContext();
+        ruleSet.Evaluate(new Order(), context);
+
+        var timeline = context.GetTimeline();
+        Assert.Equal(new[] { "HighValue", "Discount" }, timeline.Select(c => c.RuleName));
+        Assert.Equal("Customer.IsHighValue", Assert.Single(timeline[0].Changes).Path);
+        Assert.Equal("Discount", Assert.Single(timeline[1].Changes).Path);
+    }
+}
@@ -0,0 +1,175 @@
+using Winterflood.RuleEngine.Compiler.Configuration;
+using Winterflood.RuleEngine.Compiler.Configuration.Models;
+using Winterflood.RuleEngine.Engine;
+using Winterflood.RuleEngine.UnitTests.Models;
+using Xunit;
+using Assert = Xunit.Assert;
+
+namespace Winterflood.RuleEngine.UnitTests;
+
+public class RuleDependencyAnalyzerTests
+{
+    private static RuleEngineConfiguration CreateConfiguration()
+        => TestConfigurations.CreateConfiguration(
+            TestConfigurations.CreateRuleSet(
+                "Pricing",
+                TestConfigurations.CreateRule(
+                    "HighValue",
+                    "data.Customer.Age >= 18 && data.Items.Any(i => i.Price > 100)",
+                    "data.Customer.IsHighValue = true; data.Discount += 5"),
+                new StandardRuleDefinition
+                {
+                    RuleName = "Rounding",
+                    Guard = "data.Discount > 0",
+                    Conditions = "true",
+                    OnSuccess = "data.Total = Math.Round(data.Items[0].Price)"
+                },
+                TestConfigurations.CreateNestedRule("Shipping", "ShippingRules")));
+
+    [Fact]
+    public void Analyze_CollectsReadsWritesAndCalls()
+    {
+        var graph = RuleDependencyAnalyzer.Analyze(CreateConfiguration());
+
+        var highValue = graph.Rules[0];
+        Assert.Equal(new[] { "Customer.Age", "Discount", "Items" }, highValue.Reads);
+        Assert.Equal(new[] { "Customer.IsHighValue", "Discount" }, highValue.Writes);
+        Assert.Empty(highValue.Calls);
+
+        var rounding = graph.Rules[1];
+        Assert.Equal(new[] { "Discount", "Items[].Price" }, rounding.Reads);
+        Assert.Equal(new[] { "Total" }, rounding.Writes);
+        Assert.Equal(new[] { "Math.Round" }, rounding.Calls);
+
+        Assert.Equal("ShippingRules", graph.Rules[2].NestedRuleSet);
+    }
+
+    [Fact]
+    public void ImpactedBy_MatchesParentAndChildMembers()
+    {
+        var graph = RuleDependencyAnalyzer.Analyze(CreateConfiguration());
+
+        Assert.Equal(new[] { "HighValue" }, graph.ImpactedBy("Customer").Select(r => r.RuleName));
+        Assert.Equal(new[] { "HighValue", "Rounding" }, graph.ImpactedBy("Items[].Price").Select(r => r.RuleName));
+        Assert.Equal(new[] { "Rounding" }, graph.ImpactedBy("Math.Round").Select(r => r.RuleName));
+        Assert.Empty(graph.ImpactedBy("Customer.Name"));
+    }
+
+    [Fact]
+    public void Analyze_BoundAndNestedRules_ReportsPathsFromRulesetData()
+    {
+        var configuration = TestConfigurations.CreateConfiguration(
+            TestConfigurations.CreateRuleSet(
+                "Orders",
+                new NestedRuleDefinition
+                {
+                    RuleName = "CustomerChecks",
+                    RulesetName = "CustomerChecks",
+                    Adapters = ["AsRule", "Bind"],
+                    Binding = new BindingAdapter
+                    {
+                        BindSourceType = "Order",
+                        BindTargetType = "Customer",
+                        BindFactory = "return sourceData.Customer",
+                        AfterExecute = "sourceData.IsHighValue = targetData.IsHighValue;"
+                    }
+                },
+                new StandardRuleDefinition
+                {
+                    RuleName = "Pensioner",
+                    Adapters = ["Bind"],
+                    Binding = new BindingAdapter
+                    {
+                        BindSourceType = "Order",
+                        BindTargetType = "Customer",
+                        BindFactory = "return sourceData.Customer"
+                    },
+                    Conditions = "data.Age > 65"
+                }),
+            TestConfigurations.CreateRuleSet(
+                    "CustomerChecks",
+                    TestConfigurations.CreateRule("Adult", "data.Age >= 18", "data.IsHighValue = true"))
+                .WithDataType("Customer"));
+
+        var graph = RuleDependencyAnalyzer.Analyze(configuration);
+
+        var nested = graph.Rules[0];
+        Assert.Equal(new[] { "Customer", "Customer.Age", "Customer.IsHighValue" }, nested.Reads);
+        Assert.Equal(new[] { "Customer.IsHighValue", "IsHighValue" }, nested.Writes);
+        Assert.Equal(new[] { "Customer", "Customer.Age" }, graph.Rules[1].Reads);
+        Assert.Equal(new[] { "Age" }, graph.Rules[2].Reads);
+        Assert.Equal(
+            new[] { "Orders.CustomerChecks", "Orders.Pensioner" },
+            graph.ImpactedBy("Customer.Age").Select(r => $"{r.RuleSetName}.{r.RuleName}"));
+    }
+
+    [Fact]
+    public void Analyze_IncludesBranchesAndContext_AreRecorded()
+    {
+        var configuration = TestConfigurations.CreateConfiguration(
+            TestConfigurations.CreateRuleSet("Base", TestConfigurations.CreateRule("HasTotal", "data.Total > 0")),
+            TestConfigurations.CreateRuleSet(
+                    "Pricing",
+                    TestConfigurations.CreateRule(
+                            "ApplyRate",
+                            "ctx.HasChildContext(\"HasTotal\")",
+                            "ctx.SetChildContext(\"Rate\", ctx.GetData<RateService>().GetRate())")
+                        .WithErrorPolicy(RuleErrorAction.Branch, "Fallback"))
+                .Including("Base"),
+            TestConfigurations.CreateRuleSet("Fallback", TestConfigurations.CreateRule("DefaultRate", onSuccess: "data.Rate = 1")));
+
+        var graph = RuleDependencyAnalyzer.Analyze(configuration);
+
+        var inherited = graph.Rules.Single(r => r.RuleSetName == "Pricing" && r.RuleName == "HasTotal");
+        Assert.Equal("Base", inherited.IncludedFrom);
+        Assert.Equal(new[] { "Total" }, inherited.Reads);
+
+        var applyRate = graph.Rules.Single(r => r.RuleName == "ApplyRate");
+        Assert.Null(applyRate.IncludedFrom);
+        Assert.Equal("Fallback", applyRate.BranchRuleSet);
+        Assert.Equal(new[] { "Rate" }, applyRate.Writes);
+        Assert.Equal(new[] { "HasTotal", "typeof(RateService)" }, applyRate.ContextReads);
+        Assert.Equal(new[] { "Rate" }, applyRate.ContextWrites);
+        Assert.Empty(applyRate.Calls);
+        Assert.Equal(new[] { "ApplyRate" }, graph.ImpactedBy("typeof(RateService)").Select(r => r.RuleName));
+    }
+
+    [Fact]
+    public void Analyze_RulesetsNestingEachOther_ReportMembersOfEveryRulesetReached()
+    {
+        var configuration = TestConfigurations.CreateConfiguration(
+            TestConfigurations.CreateRuleSet(
+                "Orders",
+                TestConfigurations.CreateRule("HasTotal", "data.Total > 0"),
+                TestConfigurations.CreateNestedRule("Returns", "Returns")),
+            TestConfigurations.CreateRuleSet(
+                "Returns",
+                TestConfigurations.CreateRule("IsReturned", "data.Returned"),
+                TestConfigurations.CreateNestedRule("Orders", "Orders")));
+
+        var graph = RuleDependencyAnalyzer.Analyze(configuration);
+
+        var returns = graph.Rules.Single(r => r.RuleSetName == "Orders" && r.RuleName == "Returns");
+        Assert.Equal(new[] { "Returned" }, returns.Reads);
+
+        // Orders is analyzed first, so Returns must not keep the dependencies it had while Orders was still open
+        var orders = graph.Rules.Single(r => r.RuleSetName == "Returns" && r.RuleName == "Orders");
+        Assert.Equal(new[] { "Returned", "Total" }, orders.Reads);
+    }
+
+    [Fact]
+    public void Analyze_MethodsOfDeclaredVariables_AreNotRecordedAsCalls()
+    {
+        var configuration = TestConfigurations.CreateConfiguration(
+            TestConfigurations.CreateRuleSet(
+                "Pricing",
+                TestConfigurations.CreateRule(
+                    "Summarize",
+                    onSuccess: "var names = new List<string>(); names.Add(data.Name); data.Summary = Formatter.Join(names)")));
+
+        var summarize = RuleDependencyAnalyzer.Analyze(configuration).Rules.Single();
+
+        Assert.Equal(new[] { "Formatter.Join" }, summarize.Calls);
+        Assert.Equal(new[] { "Name" }, summarize.Reads);
+    }
+}
@@ -0,0 +1,179 @@
+using Microsoft.Extensions.Logging.Abstractions;
+using Moq;
+using Winterflood.RuleEngine.Engine;
+using Winterflood.RuleEngine.Engine.Adapter;
+using Winterflood.RuleEngine.Engine.Context;
+using Winterflood.RuleEngine.Engine.Data;
+using Winterflood.RuleEngine.Engine.Rule;
+using Winterflood.RuleEngine.Engine.RuleSet;
+using Xunit;
+using Assert = Xunit.Assert;
+
+namespace Winterflood.RuleEngine.UnitTests;
+
+public class RuleGroupAdapterTests
+{
+    public class TestData : IRuleData
+    {
+        public string Name { get; set; } = string.Empty;
+        public int Score { get; set; }
+        public bool Flagged { get; set; }
+    }
+
+    private class TestService;
+
+    private static IRuleSet<TestData> CreateRuleset(string name, Action<TestData> onSuccess)
+    {
+        var ruleset = new RuleSet<TestData>(name, RuleExecutionMode.All, NullLoggerFactory.Instance);
+        ruleset.AddRule(() => new Rule<TestData>($"{name}Rule", (data, _) => onSuccess(data)));
+        return ruleset;
+    }
+
+    private static IRuleSet<TestData> CreateRuleset(string name, bool result)
+    {
+        var rulesetMock = new Mock<IRuleSet<TestData>>();
+        rulesetMock.Setup(r => r.Name).Returns(name);
+        rulesetMock
+            .Setup(r => r.Evaluate(It.IsAny<TestData>(), It.IsAny<RootContext>()))
+            .Returns(result);
+        return rulesetMock.Object;
+    }
+
+    [Fact]
+    public void Evaluate_AllMode_ReturnsFalseWhenAnyRulesetFails()
+    {
+        // Arrange
+        var adapter = new RuleGroupAdapter<TestData>(
+            "RiskChecks",
+            [CreateRuleset("Fraud", true), CreateRuleset("Sanctions", false)],
+            RuleGroupMode.All);
+
+        // Act
+        var result = adapter.Evaluate(new TestData(), new RootContext());
+
+        // Assert
+        Assert.False(result);
+    }
+
+    [Fact]
+    public void Evaluate_AnyMode_ReturnsTrueWhenOneRulesetPasses()
+    {
+        // Arrange
+        var adapter = new RuleGroupAdapter<TestData>(
+            "RiskChecks",
+            [CreateRuleset("Fraud", false), CreateRuleset("Sanctions", true)],
+            RuleGroupMode.Any);
+
+        // Act
+        var result = adapter.Evaluate(new TestData(), new RootContext());
+
+        // Assert
+        Assert.True(result);
+    }
+
+    [Fact]
+    public void Evaluate_InParallel_EvaluatesEveryRulesetAndAddsContext()
+    {
+        // Arrange
+        var rulesets = Enumerable.Range(0, 8).Select(i => CreateRuleset($"RuleSet{i}", i % 2 == 0)).ToList();
+        var adapter = new RuleGroupAdapter<TestData>("ParallelChecks", rulesets, RuleGroupMode.Any, runInParallel: true);
+        var rootContext = new RootContext();
+
+        // Act
+        var result = adapter.Evaluate(new TestData(), rootContext);
+
+        // Assert
+        Assert.True(result);
+        var groupContext = rootContext.GetChildContext<RuleGroupContext>("ParallelChecks");
+        Assert.NotNull(groupContext);
+        Assert.True(groupContext!.Result);
+        Assert.Equal(8, groupContext.ChildContexts.Count);
+    }
+
+    [Fact]
+    public void Evaluate_InParallel_MergesChangesInRulesetOrder()
+    {
+        // Arrange
+        var adapter = new RuleGroupAdapter<TestData>(
+            "ParallelChecks",
+            [
+                CreateRuleset("Fraud", data => { data.Flagged = true; data.Name = "Fraud"; }),
+                CreateRuleset("Scoring", data => { data.Score = 5; data.Name = "Scoring"; })
+            ],
+            RuleGroupMode.All,
+            runInParallel: true);
+        var data = new TestData();
+
+        // Act
+        var result = adapter.Evaluate(data, new RootContext());
+
+        // Assert
+        Assert.True(result);
+        Assert.True(data.Flagged);
+        Assert.Equal(5, data.Score);
+        Assert.Equal("Scoring", data.Name);
+    }
+
+    [Fact]
+    public void Evaluate_InParallel_RethrowsErrorOfFirstFailingRuleset()
+    {
+        // Arrange
+        var adapter = new RuleGroupAdapter<TestData>(
+            "ParallelChecks",
+            [
+                CreateRuleset("Fraud", _ => throw new InvalidOperationException("Fraud service unavailable")),
+                CreateRuleset("Sanctions", _ => throw new TimeoutException("Sanctions lookup timed out"))
+            ],
+            RuleGroupMode.All,
+            runInParallel: true);
+
+        // Act & Assert
+        var exception = Assert.Throws<InvalidOperationException>(() => adapter.Evaluate(new TestData(), new RootContext()));
+        Assert.Equal("Fraud service unavailable", exception.Message);
+    }
+
+    [Fact]
+    public void Evaluate_GroupContext_SharesCallerData()
+    {
+        // Arrange
+        var service = new TestService();
+        var adapter = new RuleGroupAdapter<TestData>("RiskChecks", [CreateRuleset("Fraud", true)], RuleGroupMode.All);
+        var rootContext = new RootContext().WithData(service);
+
+        // Act
+        adapter.Evaluate(new TestData(), rootContext);
+
+        // Assert
+        var groupContext = rootContext.GetChildContext<RuleGroupContext>("RiskChecks");
+        Assert.Same(service, groupContext!.GetData<TestService>());
+    }
+
+    [Fact]
+    public void RuleSet_WithAnyGroup_PassesWhenOneRulesetFails()
+    {
+        // Arrange
+        var ruleSet = new RuleSet<TestData>("Parent", RuleExecutionMode.All, NullLoggerFactory.Instance);
+        var failing = new RuleSet<TestData>("Failing", RuleExecutionMode.All, NullLoggerFactory.Instance);
+        failing.AddRule(() => new Rule<TestData>("AlwaysFails", (_, _) => false, (_, _) => { }));
+        var passing = new RuleSet<TestData>("Passing", RuleExecutionMode.All, NullLoggerFactory.Instance);
+        passing.AddRule(() => new Rule<TestData>("AlwaysPasses", (_, _) => { }));
+
+        ruleSet.AddRule(() => new IRuleSet<TestData>[] { failing, passing }.AsGroup("Either", RuleGroupMode.Any));
+
+        // Act
+        var result = ruleSet.Evaluate(new TestData(), new RootContext());
+
+        // Assert
+        Assert.True(result);
+    }
+
+    [Fact]
+    public void Constructor_WithNullRulesets_ThrowsArgumentNullException()
+    {
+        // Arrange, Act & Assert
+        Assert.Throws<ArgumentNullException>(() =>
+        {
+            _ = new RuleGroupAdapter<TestData>("Group", null!, RuleGroupMode.All);
+        });
+    }
+}
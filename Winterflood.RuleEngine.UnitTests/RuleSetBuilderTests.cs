@@ -0,0 +1,97 @@
+using Microsoft.Extensions.Logging.Abstractions;
+using Winterflood.RuleEngine.Engine;
+using Winterflood.RuleEngine.Engine.Context;
+using Winterflood.RuleEngine.Engine.Data;
+using Winterflood.RuleEngine.Engine.Rule;
+using Xunit;
+using Assert = Xunit.Assert;
+
+namespace Winterflood.RuleEngine.UnitTests;
+
+public class RuleSetBuilderTests
+{
+    private class Order : IRuleData
+    {
+        public List<string> Evaluated { get; set; } = [];
+    }
+
+    private static IRule<Order> CreateRule(string name, string marker)
+        => new Rule<Order>(name, (data, _) => data.Evaluated.Add(marker));
+
+    private static RuleSetBuilder<Order> CreateCommonChecks()
+        => RuleSetBuilder<Order>
+            .Create("CommonChecks", RuleExecutionMode.All, NullLoggerFactory.Instance)
+            .Add("AgeCheck", () => CreateRule("AgeCheck", "Age"))
+            .Add("SanctionsCheck", () => CreateRule("SanctionsCheck", "Sanctions"));
+
+    [Fact]
+    public void Include_AddsIncludedRulesBeforeOwnRules()
+    {
+        var ruleSet = RuleSetBuilder<Order>
+            .Create("Product", RuleExecutionMode.All, NullLoggerFactory.Instance)
+            .Include(CreateCommonChecks())
+            .Add(() => CreateRule("ProductCheck", "Product"))
+            .Build();
+
+        var data = new Order();
+        ruleSet.Evaluate(data, new RootContext());
+
+        Assert.Equal(new[] { "Age", "Sanctions", "Product" }, data.Evaluated);
+    }
+
+    [Fact]
+    public void Override_ReplacesIncludedRuleInPlace()
+    {
+        var ruleSet = RuleSetBuilder<Order>
+            .Create("Product", RuleExecutionMode.All, NullLoggerFactory.Instance)
+            .Include(CreateCommonChecks())
+            .Add(() => CreateRule("ProductCheck", "Product"))
+            .Override("AgeCheck", () => CreateRule("ProductAgeCheck", "ProductAge"))
+            .Build();
+
+        var data = new Order();
+        ruleSet.Evaluate(data, new RootContext());
+
+        Assert.Equal(new[] { "ProductAge", "Sanctions", "Product" }, data.Evaluated);
+    }
+
+    [Fact]
+    public void Override_UnknownRule_ThrowsOnBuild()
+    {
+        var builder = RuleSetBuilder<Order>
+            .Create("Product", RuleExecutionMode.All, NullLoggerFactory.Instance)
+            .Include(CreateCommonChecks())
+            .Override("Missing", () => CreateRule("Replacement", "Replacement"));
+
+        Assert.Throws<InvalidOperationException>(() => builder.Build());
+    }
+
+    [Fact]
+    public void Build_WithOverride_DoesNotCreateRules()
+    {
+        var created = 0;
+        var builder = RuleSetBuilder<Order>
+            .Create("Product", RuleExecutionMode.All, NullLoggerFactory.Instance)
+            .Add("AgeCheck", () =>
+            {
+                created++;
+                return CreateRule("AgeCheck", "Age");
+            })
+            .Override("AgeCheck", () => CreateRule("ProductAgeCheck", "ProductAge"));
+
+        builder.Build();
+
+        Assert.Equal(0, created);
+    }
+
+    [Fact]
+    public void Override_RuleAddedWithoutName_ThrowsOnBuild()
+    {
+        var builder = RuleSetBuilder<Order>
+            .Create("Product", RuleExecutionMode.All, NullLoggerFactory.Instance)
+            .Add(() => CreateRule("AgeCheck", "Age"))
+            .Override("AgeCheck", () => CreateRule("ProductAgeCheck", "ProductAge"));
+
+        Assert.Throws<InvalidOperationException>(() => builder.Build());
+    }
+}
@@ -0,0 +1,94 @@
+using Microsoft.Extensions.Logging.Abstractions;
+using Winterflood.RuleEngine.Compiler.Configuration;
+using Winterflood.RuleEngine.Compiler.Runners;
+using Winterflood.RuleEngine.UnitTests.Models;
+using Xunit;
+using Assert = Xunit.Assert;
+
+namespace Winterflood.RuleEngine.UnitTests;
+
+public class RuleSetEnvironmentResolverTests
+{
+    [Fact]
+    public void ForEnvironment_PrefersEnvironmentVariantOverDefault()
+    {
+        var configuration = TestConfigurations.CreateConfiguration(
+                TestConfigurations.CreateRuleSet("Limits", TestConfigurations.CreateRule("DefaultLimit")),
+                TestConfigurations.CreateRuleSet("Limits", TestConfigurations.CreateRule("ProdLimit")).ForEnvironments("prod"))
+            .WithEnvironments("dev", "prod");
+
+        var prod = RuleSetEnvironmentResolver.ForEnvironment(configuration, "prod");
+        var dev = RuleSetEnvironmentResolver.ForEnvironment(configuration, "dev");
+
+        Assert.Equal("ProdLimit", Assert.Single(prod.RuleSets).Rules[0].RuleName);
+        Assert.Equal("DefaultLimit", Assert.Single(dev.RuleSets).Rules[0].RuleName);
+    }
+
+    [Fact]
+    public void Validate_RuleSetMissingFromEnvironment_ReportsError()
+    {
+        var configuration = TestConfigurations.CreateConfiguration(
+                TestConfigurations.CreateRuleSet("Limits", TestConfigurations.CreateRule("DevLimit")).ForEnvironments("dev"))
+            .WithEnvironments("dev", "prod");
+
+        var errors = RuleSetEnvironmentResolver.Validate(configuration);
+
+        Assert.Equal(new[] { "RuleSet 'Limits' is not defined for environment 'prod'." }, errors);
+    }
+
+    [Fact]
+    public void Validate_UndeclaredEnvironment_ReportsError()
+    {
+        var configuration = TestConfigurations.CreateConfiguration(
+                TestConfigurations.CreateRuleSet("Limits", TestConfigurations.CreateRule("DefaultLimit")),
+                TestConfigurations.CreateRuleSet("Limits", TestConfigurations.CreateRule("StagingLimit")).ForEnvironments("staging"))
+            .WithEnvironments("prod");
+
+        var errors = RuleSetEnvironmentResolver.Validate(configuration);
+
+        Assert.Equal(new[] { "RuleSet 'Limits' references undeclared environment 'staging'." }, errors);
+    }
+
+    [Fact]
+    public void ForEnvironment_AmbiguousVariants_Throws()
+    {
+        var configuration = TestConfigurations.CreateConfiguration(
+                TestConfigurations.CreateRuleSet("Limits", TestConfigurations.CreateRule("First")).ForEnvironments("prod"),
+                TestConfigurations.CreateRuleSet("Limits", TestConfigurations.CreateRule("Second")).ForEnvironments("prod"))
+            .WithEnvironments("prod");
+
+        Assert.Throws<InvalidOperationException>(() => RuleSetEnvironmentResolver.ForEnvironment(configuration, "prod"));
+    }
+
+    [Fact]
+    public void RequiresEnvironment_UnresolvedVariants_ReportsRuleSet()
+    {
+        var configuration = TestConfigurations.CreateConfiguration(
+                TestConfigurations.CreateRuleSet("Limits", TestConfigurations.CreateRule("DefaultLimit")),
+                TestConfigurations.CreateRuleSet("Limits", TestConfigurations.CreateRule("ProdLimit")).ForEnvironments("prod"),
+                TestConfigurations.CreateRuleSet("Fees", TestConfigurations.CreateRule("DefaultFee")))
+            .WithEnvironments("prod");
+
+        var required = RuleSetEnvironmentResolver.RequiresEnvironment(configuration, out var error);
+        var resolved = RuleSetEnvironmentResolver.RequiresEnvironment(RuleSetEnvironmentResolver.ForEnvironment(configuration, "prod"), out _);
+
+        Assert.True(required);
+        Assert.Contains("RuleSet 'Limits' has variants", error);
+        Assert.False(resolved);
+    }
+
+    [Fact]
+    public void ExecuteRulesetDetailed_UnresolvedVariants_Throws()
+    {
+        var configuration = TestConfigurations.CreateConfiguration(
+                TestConfigurations.CreateRuleSet("Limits", TestConfigurations.CreateRule("DefaultLimit")),
+                TestConfigurations.CreateRuleSet("Limits", TestConfigurations.CreateRule("ProdLimit")).ForEnvironments("prod"))
+            .WithEnvironments("prod")
+            .WithTypes(TestConfigurations.CreateDataModel(TestConfigurations.DataType));
+
+        var exception = Assert.Throws<InvalidOperationException>(() =>
+            RuleRunner.ExecuteRulesetDetailed(NullLoggerFactory.Instance, configuration, "Limits", "{}"));
+
+        Assert.Contains("select an environment", exception.Message);
+    }
+}
@@ -0,0 +1,97 @@
+using Microsoft.Extensions.Logging.Abstractions;
+using Winterflood.RuleEngine.Compiler.Compiler;
+using Winterflood.RuleEngine.Compiler.Configuration.Models;
+using Winterflood.RuleEngine.Compiler.Runners;
+using Winterflood.RuleEngine.Engine;
+using Winterflood.RuleEngine.Engine.Context;
+using Winterflood.RuleEngine.Engine.Data;
+using Winterflood.RuleEngine.Engine.Rule;
+using Winterflood.RuleEngine.Engine.RuleSet;
+using Winterflood.RuleEngine.UnitTests.Models;
+using Xunit;
+using Assert = Xunit.Assert;
+
+namespace Winterflood.RuleEngine.UnitTests;
+
+public class RuleSetExecutionResultTests
+{
+    public class TestData : IRuleData
+    {
+    }
+
+    private static RuleEngineConfiguration CreateConfiguration(string innerCondition)
+        => TestConfigurations.CreateConfiguration(
+                TestConfigurations.CreateRuleSet(
+                    "OrderChecks",
+                    TestConfigurations.CreateRule("UnitPrice", "100 / data.Quantity > 1").WithErrorPolicy(RuleErrorAction.Skip),
+                    TestConfigurations.CreateRule("HasQuantity", "data.Quantity >= 0"),
+                    TestConfigurations.CreateNestedRule("StockChecks", "StockChecks").WithAdapters("AsRule")),
+                TestConfigurations.CreateRuleSet("StockChecks", TestConfigurations.CreateRule("BatchSize", innerCondition)))
+            .WithTypes(TestConfigurations.CreateDataModel(TestConfigurations.DataType, ("Quantity", "int")));
+
+    private static RuleSetExecutionResult Execute(RuleEngineConfiguration configuration)
+    {
+        var compilation = SyntaxTreeCompiler.Compile(configuration, NullLoggerFactory.Instance);
+
+        return RuleRunner.ExecuteRulesetDetailed(
+            NullLoggerFactory.Instance,
+            compilation.CompiledAssembly!,
+            "OrderChecks",
+            "Order",
+            """{ "Quantity": 0 }""");
+    }
+
+    [Fact]
+    public void ExecuteRulesetDetailed_ErrorInNestedRuleSet_ReportsRuleThatRaisedIt()
+    {
+        // Act
+        var execution = Execute(CreateConfiguration("1000 / data.Quantity > 10"));
+
+        // Assert
+        Assert.Null(execution.Result);
+        Assert.NotNull(execution.FailedRule);
+        Assert.Equal("BatchSize", execution.FailedRule.RuleName);
+        Assert.Equal(execution.FailedRule.Error, execution.Error);
+        Assert.Contains(execution.Context.GetTimeline(), ruleContext => ruleContext.RuleName == "HasQuantity" && ruleContext.Result);
+    }
+
+    [Fact]
+    public void ExecuteRulesetDetailed_OnlyHandledErrors_HasNoFailedRule()
+    {
+        // Act
+        var execution = Execute(CreateConfiguration("data.Quantity == 0"));
+
+        // Assert
+        Assert.True(execution.Result);
+        Assert.Null(execution.FailedRule);
+        Assert.Null(execution.Error);
+        Assert.Contains(execution.Context.GetTimeline(), ruleContext => ruleContext.RuleName == "UnitPrice" && ruleContext.Error is not null);
+    }
+
+    [Fact]
+    public void FailedRule_NestedErrorHandledByPolicy_IsNotReportedForLaterAbort()
+    {
+        // Arrange
+        var stockChecks = new RuleSet<TestData>("StockChecks", RuleExecutionMode.All, NullLoggerFactory.Instance);
+        stockChecks.AddRule(() => new Rule<TestData>("BatchSize", (_, _) => throw new TimeoutException("Stock lookup timed out"), (_, _) => { }));
+
+        var orderChecks = new RuleSet<TestData>("OrderChecks", RuleExecutionMode.All, NullLoggerFactory.Instance);
+        orderChecks.AddRule(() => stockChecks.AsRule().OnError(RuleErrorAction.Skip));
+
+        // Aborts before any of its rules is evaluated, so the abort is not recorded against a rule
+        var misconfigured = new RuleSet<TestData>("Misconfigured", RuleExecutionMode.All, NullLoggerFactory.Instance);
+        misconfigured.AddRule(() => new Rule<TestData>("Duplicate", (_, _) => true, (_, _) => { }));
+        misconfigured.AddRule(() => new Rule<TestData>("Duplicate", (_, _) => true, (_, _) => { }));
+
+        var context = new RootContext();
+
+        // Act
+        Assert.True(orderChecks.Evaluate(new TestData(), context));
+        Assert.Throws<InvalidOperationException>(() => misconfigured.Evaluate(new TestData(), context));
+        var execution = new RuleSetExecutionResult(null, context);
+
+        // Assert
+        Assert.Null(execution.FailedRule);
+        Assert.Null(execution.Error);
+    }
+}
@@ -0,0 +1,111 @@
+using Winterflood.RuleEngine.Compiler.Configuration;
+using Winterflood.RuleEngine.UnitTests.Models;
+using Xunit;
+using Assert = Xunit.Assert;
+
+namespace Winterflood.RuleEngine.UnitTests;
+
+public class RuleSetIncludeResolverTests
+{
+    [Fact]
+    public void ResolveRules_PrependsIncludedRulesInOrder()
+    {
+        var common = TestConfigurations.CreateRuleSet("Common", TestConfigurations.CreateRule("A"), TestConfigurations.CreateRule("B"));
+        var product = TestConfigurations.CreateRuleSet("Product", TestConfigurations.CreateRule("C")).Including("Common");
+        var configuration = TestConfigurations.CreateConfiguration(common, product);
+
+        var rules = RuleSetIncludeResolver.ResolveRules(configuration, product);
+
+        Assert.Equal(new[] { "A", "B", "C" }, rules.Select(r => r.RuleName));
+    }
+
+    [Fact]
+    public void ResolveRules_OverrideReplacesIncludedRuleInPlace()
+    {
+        var common = TestConfigurations.CreateRuleSet("Common", TestConfigurations.CreateRule("A"), TestConfigurations.CreateRule("B"));
+        var product = TestConfigurations.CreateRuleSet(
+                "Product",
+                TestConfigurations.CreateRule("ProductA").Overriding("A"),
+                TestConfigurations.CreateRule("C"))
+            .Including("Common");
+        var configuration = TestConfigurations.CreateConfiguration(common, product);
+
+        var rules = RuleSetIncludeResolver.ResolveRules(configuration, product);
+
+        Assert.Equal(new[] { "ProductA", "B", "C" }, rules.Select(r => r.RuleName));
+    }
+
+    [Fact]
+    public void ResolveRules_SharedIncludeContributesRulesOnce()
+    {
+        var common = TestConfigurations.CreateRuleSet("Common", TestConfigurations.CreateRule("A"));
+        var left = TestConfigurations.CreateRuleSet("Left", TestConfigurations.CreateRule("L")).Including("Common");
+        var right = TestConfigurations.CreateRuleSet("Right", TestConfigurations.CreateRule("R")).Including("Common");
+        var product = TestConfigurations.CreateRuleSet("Product").Including("Left", "Right");
+        var configuration = TestConfigurations.CreateConfiguration(common, left, right, product);
+
+        var rules = RuleSetIncludeResolver.ResolveRules(configuration, product);
+
+        Assert.Equal(new[] { "A", "L", "R" }, rules.Select(r => r.RuleName));
+    }
+
+    [Fact]
+    public void ResolveRules_DifferentIncludedRulesWithSameName_Throws()
+    {
+        var left = TestConfigurations.CreateRuleSet("Left", TestConfigurations.CreateRule("A"));
+        var right = TestConfigurations.CreateRuleSet("Right", TestConfigurations.CreateRule("A"));
+        var product = TestConfigurations.CreateRuleSet("Product").Including("Left", "Right");
+        var configuration = TestConfigurations.CreateConfiguration(left, right, product);
+
+        Assert.Throws<InvalidOperationException>(() => RuleSetIncludeResolver.ResolveRules(configuration, product));
+    }
+
+    [Fact]
+    public void ResolveRules_OwnRuleNamedLikeIncludedRuleWithoutOverride_Throws()
+    {
+        var common = TestConfigurations.CreateRuleSet("Common", TestConfigurations.CreateRule("A"));
+        var product = TestConfigurations.CreateRuleSet("Product", TestConfigurations.CreateRule("A")).Including("Common");
+        var configuration = TestConfigurations.CreateConfiguration(common, product);
+
+        Assert.Throws<InvalidOperationException>(() => RuleSetIncludeResolver.ResolveRules(configuration, product));
+    }
+
+    [Fact]
+    public void ResolveRules_CyclicInclude_Throws()
+    {
+        var first = TestConfigurations.CreateRuleSet("First").Including("Second");
+        var second = TestConfigurations.CreateRuleSet("Second").Including("First");
+        var configuration = TestConfigurations.CreateConfiguration(first, second);
+
+        Assert.Throws<InvalidOperationException>(() => RuleSetIncludeResolver.ResolveRules(configuration, first));
+    }
+
+    [Fact]
+    public void ResolveRules_UnknownOverride_Throws()
+    {
+        var product = TestConfigurations.CreateRuleSet("Product", TestConfigurations.CreateRule("C").Overriding("Missing"));
+        var configuration = TestConfigurations.CreateConfiguration(product);
+
+        Assert.Throws<InvalidOperationException>(() => RuleSetIncludeResolver.ResolveRules(configuration, product));
+    }
+
+    [Fact]
+    public void ResolveRules_OverrideOfItself_Throws()
+    {
+        var common = TestConfigurations.CreateRuleSet("Common", TestConfigurations.CreateRule("A"));
+        var product = TestConfigurations.CreateRuleSet("Product", TestConfigurations.CreateRule("A").Overriding("A")).Including("Common");
+        var configuration = TestConfigurations.CreateConfiguration(common, product);
+
+        Assert.Throws<InvalidOperationException>(() => RuleSetIncludeResolver.ResolveRules(configuration, product));
+    }
+
+    [Fact]
+    public void ResolveRules_OverrideNamedLikeAnotherRule_Throws()
+    {
+        var common = TestConfigurations.CreateRuleSet("Common", TestConfigurations.CreateRule("A"), TestConfigurations.CreateRule("B"));
+        var product = TestConfigurations.CreateRuleSet("Product", TestConfigurations.CreateRule("B").Overriding("A")).Including("Common");
+        var configuration = TestConfigurations.CreateConfiguration(common, product);
+
+        Assert.Throws<InvalidOperationException>(() => RuleSetIncludeResolver.ResolveRules(configuration, product));
+    }
+}
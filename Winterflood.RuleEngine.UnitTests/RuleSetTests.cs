@@ -139,4 +139,41 @@ public class RuleSetTests
         Assert.True(context.ChildContexts.ContainsKey("RuleA"));
         Assert.True(context.ChildContexts.ContainsKey("RuleB"));
     }
-}
\ No newline at end of file
+
+    [Fact]
+    public void Evaluate_RuleThrows_RecordsErrorAndKeepsEarlierRuleContexts()
+    {
+        var loggerFactory = CreateLoggerFactory();
+        var ruleSet = new RuleSet<TestData>("ErrorSet", RuleExecutionMode.All, loggerFactory);
+
+        ruleSet.AddRule(() => new Rule<TestData>("RuleA", (_, _) => true, (data, _) => data.Counter++));
+        ruleSet.AddRule(() =>
+            new Rule<TestData>("RuleB", (_, _) => throw new InvalidOperationException("Lookup failed"), (_, _) => { }));
+
+        var context = new RootContext();
+
+        Assert.Throws<InvalidOperationException>(() => ruleSet.Evaluate(new TestData(), context));
+
+        Assert.True(context.GetChildContext<RuleContext>("RuleA")!.Result);
+        var failed = context.GetChildContext<RuleContext>("RuleB")!;
+        Assert.False(failed.Result);
+        Assert.Equal("Lookup failed", failed.Error);
+    }
+
+    [Fact]
+    public void Evaluate_DuplicateRuleNames_Throws()
+    {
+        var loggerFactory = CreateLoggerFactory();
+        var ruleSet = new RuleSet<TestData>("DuplicateSet", RuleExecutionMode.All, loggerFactory);
+
+        ruleSet.AddRule(() => new Rule<TestData>("RuleA", (_, _) => true, (data, _) => data.Counter++));
+        ruleSet.AddRule(() => new Rule<TestData>("RuleA", (_, _) => true, (data, _) => data.Counter++));
+
+        var data = new TestData();
+        var context = new RootContext();
+
+        Assert.Throws<InvalidOperationException>(() => ruleSet.Evaluate(data, context));
+        Assert.Empty(context.GetTimeline());
+        Assert.Equal(0, data.Counter);
+    }
+}
@@ -0,0 +1,133 @@
+using Microsoft.Extensions.Logging.Abstractions;
+using Winterflood.RuleEngine.Compiler.Compiler;
+using Winterflood.RuleEngine.Compiler.Configuration.Models;
+using Winterflood.RuleEngine.Compiler.Runners;
+using Winterflood.RuleEngine.Engine;
+using Winterflood.RuleEngine.Engine.Context;
+using Winterflood.RuleEngine.UnitTests.Models;
+using Xunit;
+using Assert = Xunit.Assert;
+
+namespace Winterflood.RuleEngine.UnitTests;
+
+public class RunOptionsTests
+{
+    private static readonly RuleEngineConfiguration Configuration = new()
+    {
+        Types =
+        [
+            new DataModelDefinition
+            {
+                Name = "Order",
+                Fields = [new FieldDefinition { Name = "Quantity", Type = "int" }]
+            }
+        ],
+        RuleSets =
+        [
+            new RuleSetDefinition
+            {
+                Name = "OrderChecks",
+                DataType = "Order",
+                Rules =
+                [
+                    new StandardRuleDefinition { RuleName = "HasQuantity", Conditions = "data.Quantity > 0" },
+                    new NestedRuleDefinition
+                    {
+                        RuleName = "StockChecks",
+                        RulesetName = "StockChecks",
+                        Adapters = ["AsRule"],
+                        ErrorPolicy = new ErrorPolicyDefinition { Action = RuleErrorAction.Skip }
+                    }
+                ]
+            },
+            new RuleSetDefinition
+            {
+                Name = "StockChecks",
+                DataType = "Order",
+                Rules = [new StandardRuleDefinition { RuleName = "InStock", Conditions = "data.Quantity < 100" }]
+            }
+        ]
+    };
+
+    private static RuleSetExecutionResult Execute(RunOptions options)
+    {
+        var compilation = SyntaxTreeCompiler.Compile(Configuration, NullLoggerFactory.Instance);
+
+        return RuleRunner.ExecuteRulesetDetailed(
+            NullLoggerFactory.Instance,
+            compilation.CompiledAssembly!,
+            "OrderChecks",
+            "Order",
+            """{ "Quantity": 10 }""",
+            new RootContext().WithOptions(options));
+    }
+
+    [Fact]
+    public void ExecuteRulesetDetailed_WithinLimits_EvaluatesRuleSet()
+    {
+        var execution = Execute(new RunOptions { MaxSteps = 3, MaxNestingDepth = 1, Deadline = DateTimeOffset.UtcNow.AddMinutes(1) });
+
+        Assert.True(execution.Result);
+        Assert.False(execution.BudgetExceeded);
+    }
+
+    [Fact]
+    public void ExecuteRulesetDetailed_MaxStepsExceeded_AbortsEvaluation()
+    {
+        var execution = Execute(new RunOptions { MaxSteps = 2 });
+
+        Assert.Null(execution.Result);
+        Assert.True(execution.BudgetExceeded);
+    }
+
+    [Fact]
+    public void ExecuteRulesetDetailed_MaxNestingDepthExceeded_IsNotHandledByErrorPolicy()
+    {
+        var execution = Execute(new RunOptions { MaxNestingDepth = 0 });
+
+        Assert.Null(execution.Result);
+        Assert.True(execution.BudgetExceeded);
+        Assert.Contains("nested", execution.Error);
+    }
+
+    [Fact]
+    public void ExecuteRulesetDetailed_MaxStepsExceeded_RecordsAbortAgainstStoppedRule()
+    {
+        var execution = Execute(new RunOptions { MaxSteps = 0 });
+
+        Assert.Null(execution.Result);
+        Assert.True(execution.BudgetExceeded);
+        Assert.Equal("HasQuantity", execution.FailedRule?.RuleName);
+        Assert.Contains("budget exceeded", execution.Error);
+    }
+
+    [Fact]
+    public void ExecuteRulesetDetailed_DeadlinePassed_AbortsEvaluation()
+    {
+        var deadline = new DateTimeOffset(2024, 1, 1, 12, 0, 0, TimeSpan.Zero);
+
+        var execution = Execute(new RunOptions
+        {
+            Deadline = deadline,
+            TimeProvider = new FixedTimeProvider(deadline.AddSeconds(1))
+        });
+
+        Assert.Null(execution.Result);
+        Assert.True(execution.BudgetExceeded);
+    }
+
+    [Fact]
+    public void ExecuteRulesetDetailed_DeadlineNotReached_EvaluatesRuleSet()
+    {
+        var deadline = new DateTimeOffset(2024, 1, 1, 12, 0, 0, TimeSpan.Zero);
+
+        var execution = Execute(new RunOptions
+        {
+            Deadline = deadline,
+            TimeProvider = new FixedTimeProvider(deadline.AddSeconds(-1))
+        });
+
+        Assert.True(execution.Result);
+        Assert.False(execution.BudgetExceeded);
+    }
+}
@@ -0,0 +1,259 @@
+using Microsoft.Extensions.Logging.Abstractions;
+using Winterflood.RuleEngine.Compiler.Compiler;
+using Winterflood.RuleEngine.Compiler.Configuration.Models;
+using Winterflood.RuleEngine.Compiler.Runners;
+using Winterflood.RuleEngine.Engine;
+using Winterflood.RuleEngine.Engine.Context;
+using Winterflood.RuleEngine.Engine.Data;
+using Winterflood.RuleEngine.Engine.Rule;
+using Winterflood.RuleEngine.Engine.RuleSet;
+using Xunit;
+using Assert = Xunit.Assert;
+
+namespace Winterflood.RuleEngine.UnitTests;
+
+public class ScoreCardTests
+{
+    public class Applicant : IRuleData
+    {
+        public int Age { get; set; }
+        public bool HomeOwner { get; set; }
+        public int MissedPayments { get; set; }
+        public decimal Score { get; set; }
+        public string? Decision { get; set; }
+    }
+
+    private static ScoreCard<Applicant> CreateScoreCard() =>
+        new ScoreCard<Applicant>("CreditScore", threshold: 50)
+            .AddFactor("Adult", (applicant, _) => applicant.Age >= 25, 30)
+            .AddFactor("HomeOwner", (applicant, _) => applicant.HomeOwner, 25)
+            .AddFactor("MissedPayments", (applicant, _) => applicant.MissedPayments > 0, -40);
+
+    [Fact]
+    public void Evaluate_ScoreReachesThreshold_PassesWithBreakdown()
+    {
+        var ruleset = new RuleSet<Applicant>("Credit", RuleExecutionMode.All, NullLoggerFactory.Instance);
+        ruleset.AddRule(CreateScoreCard);
+        var context = new RootContext();
+
+        var result = ruleset.Evaluate(new Applicant { Age = 30, HomeOwner = true }, context);
+
+        var scoreCard = (ScoreCardResult)context.GetChildContext<RuleContext>("CreditScore")!.Output!;
+        Assert.True(result);
+        Assert.Equal(55, scoreCard.Score);
+        Assert.Equal(new[] { true, true, false }, scoreCard.Factors.Select(f => f.Matched));
+    }
+
+    [Fact]
+    public void Evaluate_ScoreBelowThreshold_Fails()
+    {
+        var ruleset = new RuleSet<Applicant>("Credit", RuleExecutionMode.All, NullLoggerFactory.Instance);
+        ruleset.AddRule(CreateScoreCard);
+        var context = new RootContext();
+
+        var result = ruleset.Evaluate(new Applicant { Age = 30, HomeOwner = true, MissedPayments = 2 }, context);
+
+        Assert.False(result);
+        var ruleContext = context.GetChildContext<RuleContext>("CreditScore")!;
+        Assert.False(ruleContext.Result);
+        Assert.Equal(15, ((ScoreCardResult)ruleContext.Output!).Score);
+    }
+
+    [Fact]
+    public void Evaluate_InRuleSet_RecordsScoreOnTheRuleContext()
+    {
+        var ruleset = new RuleSet<Applicant>("Credit", RuleExecutionMode.All, NullLoggerFactory.Instance);
+        ruleset.AddRule(() => CreateScoreCard().AssignScore((applicant, score) => applicant.Score = score));
+        var context = new RootContext();
+
+        ruleset.Evaluate(new Applicant { Age = 30 }, context);
+
+        var ruleContext = Assert.Single(context.GetTimeline());
+        Assert.Equal("CreditScore", ruleContext.RuleName);
+        Assert.False(ruleContext.Result);
+        Assert.Equal(30, Assert.IsType<ScoreCardResult>(ruleContext.Output).Score);
+        Assert.Equal(30, ((Applicant)ruleContext.RuleDataAfterEvaluation!).Score);
+    }
+
+    [Fact]
+    public void Compile_ScoreCardDefinition_EvaluatesFactors()
+    {
+        var configuration = new RuleEngineConfiguration
+        {
+            Types =
+            [
+                new DataModelDefinition
+                {
+                    Name = "LoanApplicant",
+                    Fields = [new FieldDefinition { Name = "Income", Type = "int" }]
+                }
+            ],
+            RuleSets =
+            [
+                new RuleSetDefinition
+                {
+                    Name = "Affordability",
+                    DataType = "LoanApplicant",
+                    Rules =
+                    [
+                        new ScoreCardRuleDefinition
+                        {
+                            RuleName = "IncomeScore",
+                            Threshold = 20,
+                            Factors = [new ScoreCardFactorDefinition { Name = "HighIncome", Condition = "data.Income > 50000", Weight = 20.5m }]
+                        }
+                    ]
+                }
+            ]
+        };
+        var compilation = SyntaxTreeCompiler.Compile(configuration, NullLoggerFactory.Instance);
+
+        var execution = RuleRunner.ExecuteRulesetDetailed(
+            NullLoggerFactory.Instance,
+            compilation.CompiledAssembly!,
+            "Affordability",
+            "LoanApplicant",
+            """{ "Income": 60000 }""");
+
+        Assert.True(execution.Result);
+        Assert.Equal(20.5m, ((ScoreCardResult)execution.Context.GetChildContext<RuleContext>("IncomeScore")!.Output!).Score);
+    }
+
+    [Fact]
+    public void Evaluate_WithActions_AssignsScoreAndRunsOutcomeAction()
+    {
+        var ruleset = new RuleSet<Applicant>("Credit", RuleExecutionMode.All, NullLoggerFactory.Instance);
+        ruleset.AddRule(() => CreateScoreCard()
+            .AssignScore((applicant, score) => applicant.Score = score)
+            .OnSuccess((applicant, _) => applicant.Decision = "Approve")
+            .OnFailure((applicant, _) => applicant.Decision = "Refer"));
+        var approved = new Applicant { Age = 30, HomeOwner = true };
+        var referred = new Applicant { Age = 30, HomeOwner = true, MissedPayments = 1 };
+        var context = new RootContext();
+
+        ruleset.Evaluate(approved, context);
+        ruleset.Evaluate(referred, new RootContext());
+
+        Assert.Equal(55, approved.Score);
+        Assert.Equal("Approve", approved.Decision);
+        Assert.Equal(15, referred.Score);
+        Assert.Equal("Refer", referred.Decision);
+
+        var scoreCard = context.GetChildContext<RuleContext>("CreditScore")!;
+        Assert.Equal(new[] { "Score", "Decision" }, scoreCard.Changes.Select(change => change.Path));
+    }
+
+    [Fact]
+    public void Compile_ScoreCardDefinitionWithActions_AssignsScoreAndRunsOutcomeAction()
+    {
+        var configuration = new RuleEngineConfiguration
+        {
+            Types =
+            [
+                new DataModelDefinition
+                {
+                    Name = "ScoredApplicant",
+                    Fields =
+                    [
+                        new FieldDefinition { Name = "Income", Type = "int" },
+                        new FieldDefinition { Name = "Score", Type = "decimal" },
+                        new FieldDefinition { Name = "Approved", Type = "bool" }
+                    ]
+                }
+            ],
+            RuleSets =
+            [
+                new RuleSetDefinition
+                {
+                    Name = "ScoredAffordability",
+                    DataType = "ScoredApplicant",
+                    Rules =
+                    [
+                        new ScoreCardRuleDefinition
+                        {
+                            RuleName = "ScoredIncome",
+                            Threshold = 20,
+                            Factors = [new ScoreCardFactorDefinition { Name = "HighIncome", Condition = "data.Income > 50000", Weight = 20.5m }],
+                            ScoreMember = "Score",
+                            OnSuccess = "data.Approved = true",
+                            OnFailure = "data.Approved = false"
+                        }
+                    ]
+                }
+            ]
+        };
+        var compilation = SyntaxTreeCompiler.Compile(configuration, NullLoggerFactory.Instance);
+
+        var execution = RuleRunner.ExecuteRulesetDetailed(
+            NullLoggerFactory.Instance,
+            compilation.CompiledAssembly!,
+            "ScoredAffordability",
+            "ScoredApplicant",
+            """{ "Income": 60000 }""");
+
+        var scoreCard = execution.Context.GetChildContext<RuleContext>("ScoredIncome")!;
+        Assert.True(execution.Result);
+        Assert.Equal(new[] { "Score", "Approved" }, scoreCard.Changes.Select(change => change.Path));
+        Assert.Equal(20.5m, scoreCard.Changes[0].After!.GetValue<decimal>());
+    }
+
+    [Fact]
+    public void Compile_MissingScoreMember_ReportsScoreCardUnit()
+    {
+        var compilation = CompileWithScoreMember("Missing");
+
+        var unit = Assert.Single(compilation.UnitResults);
+        Assert.False(compilation.Success);
+        Assert.Null(compilation.CompiledAssembly);
+        Assert.Equal("ValidatedIncome", unit.Type);
+        Assert.Equal(
+            "ScoreCard 'ValidatedIncome' of RuleSet 'ValidatedAffordability' cannot assign its score to 'Missing': 'ValidatedApplicant' has no field named 'Missing'.",
+            unit.Message);
+    }
+
+    [Fact]
+    public void Compile_ScoreMemberNotDecimal_ReportsScoreCardUnit()
+    {
+        var compilation = CompileWithScoreMember("Income");
+
+        var unit = Assert.Single(compilation.UnitResults);
+        Assert.False(compilation.Success);
+        Assert.Equal(
+            "ScoreCard 'ValidatedIncome' of RuleSet 'ValidatedAffordability' cannot assign its score to 'Income': the field is of type 'int', which cannot hold a decimal score.",
+            unit.Message);
+    }
+
+    private static CompilationResult CompileWithScoreMember(string scoreMember)
+    {
+        var configuration = new RuleEngineConfiguration
+        {
+            Types =
+            [
+                new DataModelDefinition
+                {
+                    Name = "ValidatedApplicant",
+                    Fields = [new FieldDefinition { Name = "Income", Type = "int" }]
+                }
+            ],
+            RuleSets =
+            [
+                new RuleSetDefinition
+                {
+                    Name = "ValidatedAffordability",
+                    DataType = "ValidatedApplicant",
+                    Rules =
+                    [
+                        new ScoreCardRuleDefinition
+                        {
+                            RuleName = "ValidatedIncome",
+                            Threshold = 20,
+                            ScoreMember = scoreMember
+                        }
+                    ]
+                }
+            ]
+        };
+
+        return SyntaxTreeCompiler.Compile(configuration, NullLoggerFactory.Instance);
+    }
+}
@@ -0,0 +1,115 @@
+using Microsoft.Extensions.Logging;
+using Winterflood.RuleEngine.Compiler.Configuration;
+using Winterflood.RuleEngine.Compiler.Configuration.Models;
+
+namespace Winterflood.RuleEngine.Compiler.Compiler;
+
+/// <summary>
+/// Caches compiled rule engine configurations so that identical configurations are only compiled once.
+/// </summary>
+/// <remarks>
+/// Configurations are keyed by a SHA-256 hash of their serialized definition, and the least recently used
+/// entry is evicted once the cache is full. Only successful compilations are cached.
+/// Evicted compilations are only removed from the cache and are not unloaded, as callers may still be executing
+/// their rulesets. The cache is safe to share between threads.
+/// </remarks>
+public class CompilationCache
+{
+    private readonly int _capacity;
+    private readonly object _lock = new();
+    private readonly Dictionary<string, LinkedListNode<(string Key, CompilationResult Result)>> _entries = new();
+    private readonly LinkedList<(string Key, CompilationResult Result)> _usage = new();
+
+    /// <summary>
+    /// Initializes a new instance of the <see cref="CompilationCache"/> class.
+    /// </summary>
+    /// <param name="capacity">The maximum number of compiled configurations to keep.</param>
+    /// <exception cref="ArgumentOutOfRangeException">Thrown when <paramref name="capacity"/> is less than one.</exception>
+    public CompilationCache(int capacity = 16)
+    {
+        ArgumentOutOfRangeException.ThrowIfLessThan(capacity, 1);
+        _capacity = capacity;
+    }
+
+    /// <summary>
+    /// Gets the number of compiled configurations currently cached.
+    /// </summary>
+    public int Count
+    {
+        get
+        {
+            lock (_lock)
+                return _entries.Count;
+        }
+    }
+
+    /// <summary>
+    /// Returns the cached compilation of the configuration, compiling and caching it if it is not cached yet.
+    /// </summary>
+    /// <param name="configuration">The configuration to compile.</param>
+    /// <param name="loggerFactory">The logger factory for logging compilation details.</param>
+    /// <returns>The compilation result for the configuration.</returns>
+    public CompilationResult GetOrCompile(RuleEngineConfiguration configuration, ILoggerFactory loggerFactory)
+    {
+        var logger = loggerFactory.CreateLogger<CompilationCache>();
+        var key = RuleDefinitionParser.ComputeHash(configuration, loggerFactory);
+
+        lock (_lock)
+        {
+            if (_entries.TryGetValue(key, out var node))
+            {
+                _usage.Remove(node);
+                _usage.AddFirst(node);
+
+                logger.LogInformation("[Cache Hit] Configuration={ConfigurationHash}", key);
+                return node.Value.Result;
+            }
+        }
+
+        logger.LogInformation("[Cache Miss] Configuration={ConfigurationHash}", key);
+
+        // Compile outside the lock so that slow compilations do not block lookups of other configurations
+        var result = SyntaxTreeCompiler.Compile(configuration, loggerFactory);
+        if (!result.Success)
+            return result;
+
+        var evicted = false;
+
+        lock (_lock)
+        {
+            if (_entries.TryGetValue(key, out var existing))
+            {
+                // Another thread compiled the same configuration first, so this compilation is not needed
+                result.Unload();
+                return existing.Value.Result;
+            }
+
+            _entries[key] = _usage.AddFirst((key, result));
+
+            if (_entries.Count > _capacity)
+            {
+                var leastRecentlyUsed = _usage.Last!;
+                _usage.RemoveLast();
+                _entries.Remove(leastRecentlyUsed.Value.Key);
+                evicted = true;
+            }
+        }
+
+        if (evicted)
+            logger.LogInformation("[Cache Evicted] Capacity={Capacity}", _capacity);
+
+        return result;
+    }
+
+    /// <summary>
+    /// Removes all compiled configurations from the cache. Callers still holding a compilation can keep using it.
+    /// </summary>
+    public void Clear()
+    {
+        lock (_lock)
+        {
+            _entries.Clear();
+            _usage.Clear();
+        }
+    }
+}
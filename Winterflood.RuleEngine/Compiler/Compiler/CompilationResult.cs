@@ -1,4 +1,5 @@
 using System.Reflection;
+using System.Runtime.Loader;
 
 namespace Winterflood.RuleEngine.Compiler.Compiler;
 
@@ -26,11 +27,19 @@ public class CompilationUnitResult(
 /// </summary>
 /// <param name="unitResults"></param>
 /// <param name="compiledAssembly"></param>
+/// <param name="loadContext">The collectible context the assembly was loaded into, if any.</param>
 public class CompilationResult(
     List<CompilationUnitResult> unitResults,
-    Assembly? compiledAssembly)
+    Assembly? compiledAssembly,
+    AssemblyLoadContext? loadContext = null)
 {
     public bool Success => UnitResults.All(r => r.Success);
     public List<CompilationUnitResult> UnitResults { get; } = unitResults;
     public Assembly? CompiledAssembly { get; } = compiledAssembly;
+
+    /// <summary>
+    /// Starts unloading the compiled assembly. The assembly stays usable, and is only unloaded
+    /// once nothing references it or its types any more.
+    /// </summary>
+    internal void Unload() => loadContext?.Unload();
 }
\ No newline at end of file
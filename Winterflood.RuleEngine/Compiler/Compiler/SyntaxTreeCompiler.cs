@@ -1,8 +1,10 @@
 using System.Reflection;
+using System.Runtime.Loader;
 using Microsoft.CodeAnalysis;
 using Microsoft.CodeAnalysis.CSharp;
 using Microsoft.CodeAnalysis.CSharp.Syntax;
 using Microsoft.Extensions.Logging;
+using Winterflood.RuleEngine.Compiler.Configuration;
 using Winterflood.RuleEngine.Compiler.Configuration.Models;
 using Winterflood.RuleEngine.Constants;
 using Winterflood.RuleEngine.Engine;
@@ -23,7 +25,13 @@ public static class SyntaxTreeCompiler
     /// </summary>
     /// <param name="configuration">The configuration containing rule definitions and types.</param>
     /// <param name="loggerFactory">The logger factory for logging compilation details.</param>
-    /// <returns>The compiled assembly containing the rule engine logic.</returns>
+    /// <returns>
+    /// The compiled assembly containing the rule engine logic, or a failed unit for each scorecard
+    /// whose score member is not a field of its ruleset's data type that can hold a decimal.
+    /// </returns>
+    /// <exception cref="InvalidOperationException">
+    /// Thrown when the configuration contains variants of a ruleset that have not been resolved for an environment.
+    /// </exception>
     public static CompilationResult Compile(
         RuleEngineConfiguration configuration,
         ILoggerFactory loggerFactory)
@@ -35,6 +43,18 @@ public static class SyntaxTreeCompiler
 
         try
         {
+            // Variants of a ruleset would generate the same class, so they must be resolved for an environment first
+            if (RuleSetEnvironmentResolver.RequiresEnvironment(configuration, out var variantError))
+                throw new InvalidOperationException(variantError);
+
+            // A score member that is missing or cannot hold a decimal would otherwise only show up as an error in generated code
+            var scoreMemberErrors = ValidateScoreMembers(configuration);
+            if (scoreMemberErrors.Count != 0)
+            {
+                logger.LogError("Compilation failed with ErrorCount={ErrorCount} invalid score members.", scoreMemberErrors.Count);
+                return new CompilationResult(scoreMemberErrors, null);
+            }
+
             var typeSyntaxTrees =
                 configuration.Types
                     .Select(model =>
@@ -45,7 +65,7 @@ public static class SyntaxTreeCompiler
 
             syntaxTrees.AddRange(typeSyntaxTrees);
 
-            Compile(syntaxTrees, logger);
+            Compile(syntaxTrees, logger).Unload();
 
             var ruleSyntaxTrees =
                 configuration.RuleSets
@@ -63,7 +83,7 @@ public static class SyntaxTreeCompiler
 
             syntaxTrees.AddRange(ruleSyntaxTrees);
 
-            Compile(syntaxTrees, logger);
+            Compile(syntaxTrees, logger).Unload();
 
             var ruleSetSyntaxTrees =
                 configuration.RuleSets
@@ -72,10 +92,13 @@ public static class SyntaxTreeCompiler
                             ruleSet.Name,
                             ruleSet.DataType,
                             ruleSet.ExecutionMode,
-                            ruleSet.Rules,
+                            RuleSetIncludeResolver.ResolveRules(configuration, ruleSet),
                             logger));
 
             syntaxTrees.AddRange(ruleSetSyntaxTrees);
+
+            // Stamp the configuration hash into the assembly so that decisions can prove which rules they ran
+            syntaxTrees.Add(BuildConfigurationHashSyntaxTree(RuleDefinitionParser.ComputeHash(configuration, loggerFactory)));
         }
         catch (Exception ex)
         {
@@ -204,12 +227,91 @@ public static class SyntaxTreeCompiler
         {
             logger.LogInformation("Compilation successful.");
             ms.Seek(0, SeekOrigin.Begin);
-            return new CompilationResult(unitResults, Assembly.Load(ms.ToArray()));
+
+            // Load into a collectible context so that the assembly can be unloaded once it is no longer needed
+            var loadContext = new AssemblyLoadContext("DynamicRules", isCollectible: true);
+            return new CompilationResult(unitResults, loadContext.LoadFromStream(ms), loadContext);
         }
 
         return new CompilationResult(unitResults, null);
     }
 
+    /// <summary>
+    /// Checks that every scorecard assigns its score to a field of its ruleset's data type that can hold a decimal.
+    /// </summary>
+    /// <param name="configuration">The configuration to check.</param>
+    /// <returns>A failed unit for each scorecard with an invalid score member.</returns>
+    private static List<CompilationUnitResult> ValidateScoreMembers(RuleEngineConfiguration configuration)
+    {
+        var errors = new List<CompilationUnitResult>();
+
+        foreach (var ruleSet in configuration.RuleSets)
+        {
+            foreach (var scoreCard in ruleSet.Rules.OfType<ScoreCardRuleDefinition>())
+            {
+                if (string.IsNullOrWhiteSpace(scoreCard.ScoreMember))
+                    continue;
+
+                var member = scoreCard.ScoreMember.Trim();
+                var error = GetScoreMemberError(configuration, ruleSet.DataType, member);
+                if (error is null)
+                    continue;
+
+                errors.Add(new CompilationUnitResult(
+                    type: scoreCard.RuleName,
+                    success: false,
+                    message: $"ScoreCard '{scoreCard.RuleName}' of RuleSet '{ruleSet.Name}' cannot assign its score to '{member}': {error}.",
+                    meta: member));
+            }
+        }
+
+        return errors;
+    }
+
+    private static string? GetScoreMemberError(RuleEngineConfiguration configuration, string dataType, string member)
+    {
+        // Members such as Customer.Score are followed through the fields of the data types in the configuration
+        var typeName = dataType.Trim();
+        foreach (var segment in member.Split('.').Select(segment => segment.Trim()))
+        {
+            var model = configuration.Types.FirstOrDefault(type => type.Name == typeName.TrimEnd('?'));
+            if (model is null)
+                return $"'{typeName}' is not a data type defined in the configuration";
+
+            var field = model.Fields.FirstOrDefault(field => field.Name == segment);
+            if (field is null)
+                return $"'{model.Name}' has no field named '{segment}'";
+
+            typeName = field.Type.Trim();
+        }
+
+        return typeName.TrimEnd('?') is "decimal" or "Decimal" or "System.Decimal"
+            ? null
+            : $"the field is of type '{typeName}', which cannot hold a decimal score";
+    }
+
+    /// <summary>
+    /// Reads the hash of the configuration an assembly was compiled from.
+    /// </summary>
+    /// <param name="assembly">An assembly compiled by <see cref="Compile(RuleEngineConfiguration, ILoggerFactory)"/>.</param>
+    /// <returns>The configuration hash, or <c>null</c> if the assembly was not compiled from a configuration.</returns>
+    public static string? GetConfigurationHash(Assembly assembly)
+    {
+        return assembly
+            .GetCustomAttributes<AssemblyMetadataAttribute>()
+            .FirstOrDefault(attribute => attribute.Key == CompilerArtifactConstants.ConfigurationHashMetadataKey)
+            ?.Value;
+    }
+
+    /// <summary>
+    /// Builds the assembly attribute recording the hash of the compiled configuration.
+    /// </summary>
+    private static CompilationUnitSyntax BuildConfigurationHashSyntaxTree(string configurationHash)
+    {
+        return SyntaxFactory.ParseCompilationUnit(
+            $"[assembly: System.Reflection.AssemblyMetadata(\"{CompilerArtifactConstants.ConfigurationHashMetadataKey}\", \"{configurationHash}\")]");
+    }
+
     /// <summary>
     /// Extracts the first class name found in the syntax tree.
     /// </summary>
@@ -461,7 +563,7 @@ public static class SyntaxTreeCompiler
                                                     ]))))))));
 
         var statements = new List<StatementSyntax> { rulesetDeclaration };
-        statements.AddRange(rules.Select(GenerateRuleStatement));
+        statements.AddRange(rules.Select(rule => GenerateRuleStatement(rule, dataType)));
         statements.Add(SyntaxFactory.ReturnStatement(SyntaxFactory.IdentifierName(rulesetVariableName)));
 
         var ruleSetMethod =
@@ -510,7 +612,7 @@ public static class SyntaxTreeCompiler
 
         return syntaxTree;
 
-        static StatementSyntax GenerateRuleStatement(RuleDefinition rule)
+        static StatementSyntax GenerateRuleStatement(RuleDefinition rule, string dataType)
         {
             // Step 1: Create base rule expression
             ExpressionSyntax baseExpression = rule switch
@@ -530,6 +632,8 @@ public static class SyntaxTreeCompiler
                             SyntaxFactory.SingletonSeparatedList(
                                 SyntaxFactory.Argument(SyntaxFactory.IdentifierName("loggerFactory"))))),
 
+                ScoreCardRuleDefinition scoreCard => GenerateScoreCardExpression(scoreCard, dataType),
+
                 _ => throw new InvalidOperationException($"Unsupported rule type: {rule.GetType().Name}")
             };
 
@@ -600,6 +704,73 @@ public static class SyntaxTreeCompiler
                         .WithArgumentList(SyntaxFactory.ArgumentList(SyntaxFactory.SeparatedList(bindArgs)));
             }
 
+            // 2.4 Apply the error policy if present
+            if (rule.ErrorPolicy is not null)
+            {
+                if (rule.ErrorPolicy.Action == RuleErrorAction.Retry && rule.ErrorPolicy.RetryCount < 1)
+                    throw new InvalidOperationException($"Error policy of Rule '{rule.RuleName}' requires a RetryCount of at least 1 to retry.");
+
+                var errorPolicyArgs = new List<ArgumentSyntax>
+                {
+                    SyntaxFactory.Argument(
+                        SyntaxFactory.MemberAccessExpression(
+                            SyntaxKind.SimpleMemberAccessExpression,
+                            SyntaxFactory.IdentifierName(nameof(RuleErrorAction)),
+                            SyntaxFactory.IdentifierName(rule.ErrorPolicy.Action.ToString()))),
+                    SyntaxFactory.Argument(
+                        SyntaxFactory.LiteralExpression(
+                            SyntaxKind.NumericLiteralExpression,
+                            SyntaxFactory.Literal(rule.ErrorPolicy.RetryCount)))
+                };
+
+                if (rule.ErrorPolicy.Action == RuleErrorAction.Branch)
+                {
+                    if (string.IsNullOrWhiteSpace(rule.ErrorPolicy.RuleSetName))
+                        throw new InvalidOperationException($"Error policy of Rule '{rule.RuleName}' requires a RuleSetName to branch to.");
+
+                    errorPolicyArgs.Add(SyntaxFactory.Argument(
+                        SyntaxFactory.InvocationExpression(
+                                SyntaxFactory.MemberAccessExpression(
+                                    SyntaxKind.SimpleMemberAccessExpression,
+                                    SyntaxFactory.IdentifierName(rule.ErrorPolicy.RuleSetName),
+                                    SyntaxFactory.IdentifierName("Create")))
+                            .WithArgumentList(SyntaxFactory.ArgumentList(
+                                SyntaxFactory.SingletonSeparatedList(
+                                    SyntaxFactory.Argument(SyntaxFactory.IdentifierName("loggerFactory")))))));
+                }
+
+                baseExpression =
+                    SyntaxFactory
+                        .InvocationExpression(
+                            SyntaxFactory.MemberAccessExpression(
+                                SyntaxKind.SimpleMemberAccessExpression,
+                                baseExpression,
+                                SyntaxFactory.IdentifierName("OnError")))
+                        .WithArgumentList(SyntaxFactory.ArgumentList(SyntaxFactory.SeparatedList(errorPolicyArgs)));
+            }
+
+            // 2.5 Apply the guard outermost so it is checked against the ruleset's data
+            if (!string.IsNullOrWhiteSpace(rule.Guard))
+            {
+                baseExpression =
+                    SyntaxFactory
+                        .InvocationExpression(
+                            SyntaxFactory.MemberAccessExpression(
+                                SyntaxKind.SimpleMemberAccessExpression,
+                                baseExpression,
+                                SyntaxFactory.IdentifierName("WithGuard")))
+                        .WithArgumentList(SyntaxFactory.ArgumentList(
+                            SyntaxFactory.SingletonSeparatedList(
+                                SyntaxFactory.Argument(
+                                    SyntaxFactory.ParenthesizedLambdaExpression()
+                                        .WithParameterList(SyntaxFactory.ParameterList(
+                                            SyntaxFactory.SeparatedList([
+                                                SyntaxFactory.Parameter(SyntaxFactory.Identifier("data")),
+                                                SyntaxFactory.Parameter(SyntaxFactory.Identifier("ctx"))
+                                            ])))
+                                        .WithExpressionBody(SyntaxFactory.ParseExpression(rule.Guard))))));
+            }
+
             // Step 3: Return final rule statement
             return SyntaxFactory.ExpressionStatement(
                 SyntaxFactory.InvocationExpression(
@@ -614,6 +785,99 @@ public static class SyntaxTreeCompiler
                                     .WithBlock(SyntaxFactory.Block(
                                         SyntaxFactory.ReturnStatement(baseExpression))))))));
         }
+
+        static ExpressionSyntax GenerateScoreCardExpression(ScoreCardRuleDefinition scoreCard, string dataType)
+        {
+            // new ScoreCard<{dataType}>("{name}", {threshold})
+            ExpressionSyntax expression =
+                SyntaxFactory
+                    .ObjectCreationExpression(SyntaxFactory.ParseTypeName($"ScoreCard<{dataType}>"))
+                    .WithArgumentList(SyntaxFactory.ArgumentList(
+                        SyntaxFactory.SeparatedList([
+                            SyntaxFactory.Argument(SyntaxFactory.LiteralExpression(
+                                SyntaxKind.StringLiteralExpression,
+                                SyntaxFactory.Literal(scoreCard.RuleName))),
+                            SyntaxFactory.Argument(SyntaxFactory.LiteralExpression(
+                                SyntaxKind.NumericLiteralExpression,
+                                SyntaxFactory.Literal(scoreCard.Threshold)))
+                        ])));
+
+            // .AddFactor("{name}", (data, ctx) => {condition}, {weight}) for each factor
+            foreach (var factor in scoreCard.Factors)
+            {
+                expression =
+                    SyntaxFactory
+                        .InvocationExpression(
+                            SyntaxFactory.MemberAccessExpression(
+                                SyntaxKind.SimpleMemberAccessExpression,
+                                expression,
+                                SyntaxFactory.IdentifierName("AddFactor")))
+                        .WithArgumentList(SyntaxFactory.ArgumentList(
+                            SyntaxFactory.SeparatedList([
+                                SyntaxFactory.Argument(SyntaxFactory.LiteralExpression(
+                                    SyntaxKind.StringLiteralExpression,
+                                    SyntaxFactory.Literal(factor.Name))),
+                                SyntaxFactory.Argument(
+                                    SyntaxFactory.ParenthesizedLambdaExpression()
+                                        .WithParameterList(SyntaxFactory.ParameterList(
+                                            SyntaxFactory.SeparatedList([
+                                                SyntaxFactory.Parameter(SyntaxFactory.Identifier("data")),
+                                                SyntaxFactory.Parameter(SyntaxFactory.Identifier("ctx"))
+                                            ])))
+                                        .WithExpressionBody(SyntaxFactory.ParseExpression(factor.Condition))),
+                                SyntaxFactory.Argument(SyntaxFactory.LiteralExpression(
+                                    SyntaxKind.NumericLiteralExpression,
+                                    SyntaxFactory.Literal(factor.Weight)))
+                            ])));
+            }
+
+            // .AssignScore((data, score) => data.{member} = score)
+            if (!string.IsNullOrWhiteSpace(scoreCard.ScoreMember))
+            {
+                expression = ChainScoreCardCall(
+                    expression,
+                    "AssignScore",
+                    SyntaxFactory.ParseExpression($"(data, score) => data.{scoreCard.ScoreMember.Trim()} = score"));
+            }
+
+            // .OnSuccess((data, ctx) => { ... }) and .OnFailure((data, ctx) => { ... })
+            if (!string.IsNullOrWhiteSpace(scoreCard.OnSuccess))
+                expression = ChainScoreCardCall(expression, "OnSuccess", GenerateScoreCardAction(scoreCard.OnSuccess));
+
+            if (!string.IsNullOrWhiteSpace(scoreCard.OnFailure))
+                expression = ChainScoreCardCall(expression, "OnFailure", GenerateScoreCardAction(scoreCard.OnFailure));
+
+            return expression;
+        }
+
+        static ExpressionSyntax ChainScoreCardCall(ExpressionSyntax expression, string methodName, ExpressionSyntax argument)
+        {
+            return SyntaxFactory
+                .InvocationExpression(
+                    SyntaxFactory.MemberAccessExpression(
+                        SyntaxKind.SimpleMemberAccessExpression,
+                        expression,
+                        SyntaxFactory.IdentifierName(methodName)))
+                .WithArgumentList(SyntaxFactory.ArgumentList(
+                    SyntaxFactory.SingletonSeparatedList(SyntaxFactory.Argument(argument))));
+        }
+
+        static ExpressionSyntax GenerateScoreCardAction(string statements)
+        {
+            // Statements are split the same way as the actions of standard rules
+            return SyntaxFactory
+                .ParenthesizedLambdaExpression()
+                .WithParameterList(SyntaxFactory.ParameterList(
+                    SyntaxFactory.SeparatedList([
+                        SyntaxFactory.Parameter(SyntaxFactory.Identifier("data")),
+                        SyntaxFactory.Parameter(SyntaxFactory.Identifier("ctx"))
+                    ])))
+                .WithBlock(SyntaxFactory.Block(
+                    statements
+                        .Split(';')
+                        .Where(line => !string.IsNullOrWhiteSpace(line))
+                        .Select(line => SyntaxFactory.ParseStatement(line.Trim() + ";"))));
+        }
     }
 
     /// <summary>
@@ -0,0 +1,25 @@
+using System.Security.Cryptography;
+using System.Text;
+
+namespace Winterflood.RuleEngine.Compiler.Configuration.Bundle;
+
+/// <summary>
+/// Signs and verifies rule bundles with an HMAC-SHA256 shared key.
+/// </summary>
+/// <param name="key">The shared key used to sign and verify bundles.</param>
+public class HmacRuleBundleVerifier(byte[] key) : IRuleBundleVerifier
+{
+    /// <summary>
+    /// Signs a configuration hash.
+    /// </summary>
+    /// <param name="hash">The configuration hash to sign.</param>
+    /// <returns>The hex encoded signature.</returns>
+    public string Sign(string hash)
+        => Convert.ToHexString(HMACSHA256.HashData(key, Encoding.UTF8.GetBytes(hash)));
+
+    /// <inheritdoc />
+    public bool Verify(string hash, string signature)
+        => CryptographicOperations.FixedTimeEquals(
+            Encoding.UTF8.GetBytes(Sign(hash)),
+            Encoding.UTF8.GetBytes(signature.ToUpperInvariant()));
+}
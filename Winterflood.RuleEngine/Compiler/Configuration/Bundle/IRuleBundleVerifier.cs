@@ -0,0 +1,15 @@
+namespace Winterflood.RuleEngine.Compiler.Configuration.Bundle;
+
+/// <summary>
+/// Verifies the signature of a <see cref="RuleBundle"/>.
+/// </summary>
+public interface IRuleBundleVerifier
+{
+    /// <summary>
+    /// Verifies that a signature was produced for the given configuration hash by a trusted signer.
+    /// </summary>
+    /// <param name="hash">The configuration hash that was signed.</param>
+    /// <param name="signature">The signature to verify.</param>
+    /// <returns><c>true</c> if the signature is valid; otherwise, <c>false</c>.</returns>
+    bool Verify(string hash, string signature);
+}
@@ -0,0 +1,27 @@
+namespace Winterflood.RuleEngine.Compiler.Configuration.Bundle;
+
+/// <summary>
+/// Represents a rule engine configuration packaged with a hash of its content and an optional signature,
+/// allowing the exact approved rules to be verified when they are loaded.
+/// </summary>
+public class RuleBundle
+{
+    /// <summary>
+    /// Gets or sets the hash of <see cref="Configuration"/>, as computed by <see cref="RuleBundleLoader.ComputeHash"/>.
+    /// </summary>
+    public string Hash { get; set; } = string.Empty;
+
+    /// <summary>
+    /// Gets or sets the signature of <see cref="Hash"/>, if the bundle was signed.
+    /// </summary>
+    public string? Signature { get; set; }
+
+    /// <summary>
+    /// Gets or sets the packaged configuration JSON, exactly as it was approved.
+    /// </summary>
+    /// <remarks>
+    /// The configuration is kept as text rather than as a parsed model, so that the hash and signature cover
+    /// every byte that was approved and remain valid when the configuration model gains new properties.
+    /// </remarks>
+    public string Configuration { get; set; } = string.Empty;
+}
@@ -0,0 +1,140 @@
+using System.Security.Cryptography;
+using System.Text;
+using System.Text.Json;
+using Microsoft.Extensions.Logging;
+using Winterflood.RuleEngine.Compiler.Configuration.Models;
+
+namespace Winterflood.RuleEngine.Compiler.Configuration.Bundle;
+
+/// <summary>
+/// Creates and loads integrity-checked <see cref="RuleBundle"/> instances.
+/// </summary>
+public static class RuleBundleLoader
+{
+    /// <summary>
+    /// Packages a configuration into a bundle containing its content hash and an optional signature.
+    /// </summary>
+    /// <param name="configuration">The configuration to package.</param>
+    /// <param name="loggerFactory">Factory for structured logging.</param>
+    /// <param name="sign">Optional function that signs the configuration hash.</param>
+    /// <returns>The JSON representation of the bundle.</returns>
+    public static string CreateBundle(
+        RuleEngineConfiguration configuration,
+        ILoggerFactory loggerFactory,
+        Func<string, string>? sign = null)
+    {
+        var configurationJson = JsonSerializer.Serialize(configuration, RuleDefinitionParser.CreateSerializerOptions(loggerFactory));
+        return CreateBundle(configurationJson, sign);
+    }
+
+    /// <summary>
+    /// Packages configuration JSON, such as the content of an approved configuration file, into a bundle
+    /// containing its content hash and an optional signature.
+    /// </summary>
+    /// <param name="configurationJson">The configuration JSON to package, kept exactly as given.</param>
+    /// <param name="sign">Optional function that signs the configuration hash.</param>
+    /// <returns>The JSON representation of the bundle.</returns>
+    public static string CreateBundle(string configurationJson, Func<string, string>? sign = null)
+    {
+        var hash = ComputeHash(configurationJson);
+
+        var bundle = new RuleBundle
+        {
+            Hash = hash,
+            Signature = sign?.Invoke(hash),
+            Configuration = configurationJson
+        };
+
+        return JsonSerializer.Serialize(bundle, new JsonSerializerOptions { WriteIndented = true });
+    }
+
+    /// <summary>
+    /// Computes the hash of bundled configuration JSON.
+    /// </summary>
+    /// <param name="configurationJson">The configuration JSON.</param>
+    /// <returns>The hex encoded SHA-256 hash of the UTF-8 encoded configuration JSON.</returns>
+    public static string ComputeHash(string configurationJson)
+        => Convert.ToHexString(SHA256.HashData(Encoding.UTF8.GetBytes(configurationJson)));
+
+    /// <summary>
+    /// Loads a bundle and verifies that its configuration matches the recorded hash and signature.
+    /// </summary>
+    /// <remarks>
+    /// The hash is computed over the bundled configuration JSON before it is parsed, so any change to it,
+    /// including to properties the configuration model does not know about, is detected.
+    /// The loaded configuration records the outcome in <see cref="RuleEngineConfiguration.Bundle"/>.
+    /// </remarks>
+    /// <param name="json">The JSON representation of the bundle.</param>
+    /// <param name="verifier">Optional verifier used to check the bundle's signature.</param>
+    /// <param name="loggerFactory">Factory for structured logging.</param>
+    /// <param name="configuration">
+    /// The bundled configuration when its hash matches and, if a verifier is supplied, its signature is valid;
+    /// otherwise, <c>null</c>.
+    /// </param>
+    /// <returns>The outcome of verifying the bundle.</returns>
+    public static RuleBundleVerification LoadBundle(
+        string json,
+        IRuleBundleVerifier? verifier,
+        ILoggerFactory loggerFactory,
+        out RuleEngineConfiguration? configuration)
+    {
+        var logger = loggerFactory.CreateLogger(nameof(RuleBundleLoader));
+        configuration = null;
+
+        RuleBundle? bundle;
+        try
+        {
+            bundle = JsonSerializer.Deserialize<RuleBundle>(json, new JsonSerializerOptions { PropertyNameCaseInsensitive = true });
+        }
+        catch (JsonException ex)
+        {
+            logger.LogError(ex, "Failed to parse RuleBundle.");
+            return RuleBundleVerification.Invalid;
+        }
+
+        if (bundle is null)
+        {
+            logger.LogError("Failed to parse RuleBundle: Deserialized bundle is null.");
+            return RuleBundleVerification.Invalid;
+        }
+
+        // A bundle without a configuration, including one whose configuration is null, has nothing to verify
+        if (string.IsNullOrWhiteSpace(bundle.Configuration))
+        {
+            logger.LogError("Failed to parse RuleBundle: Bundle has no configuration.");
+            return RuleBundleVerification.Invalid;
+        }
+
+        var hash = ComputeHash(bundle.Configuration);
+        if (!string.Equals(hash, bundle.Hash, StringComparison.OrdinalIgnoreCase))
+        {
+            logger.LogError("RuleBundle hash mismatch: Expected={ExpectedHash} Actual={ActualHash}", bundle.Hash, hash);
+            return RuleBundleVerification.HashMismatch;
+        }
+
+        var verification = RuleBundleVerification.HashVerified;
+        if (verifier is not null)
+        {
+            if (bundle.Signature is null || !verifier.Verify(hash, bundle.Signature))
+            {
+                logger.LogError("RuleBundle signature rejected: Hash={Hash}", hash);
+                return RuleBundleVerification.InvalidSignature;
+            }
+
+            verification = RuleBundleVerification.Verified;
+        }
+
+        var parsed = RuleDefinitionParser.ParseConfiguration(bundle.Configuration, loggerFactory);
+        if (parsed is null)
+        {
+            logger.LogError("RuleBundle configuration could not be parsed: Hash={Hash}", hash);
+            return RuleBundleVerification.Invalid;
+        }
+
+        logger.LogInformation("RuleBundle verified: Hash={Hash} Verification={Verification}", hash, verification);
+
+        parsed.Bundle = new RuleBundleMetadata { Hash = hash, Verification = verification };
+        configuration = parsed;
+        return verification;
+    }
+}
@@ -0,0 +1,21 @@
+using System.Text.Json.Serialization;
+
+namespace Winterflood.RuleEngine.Compiler.Configuration.Bundle;
+
+/// <summary>
+/// Describes the bundle a configuration was loaded from and how it was verified, so that decisions made with
+/// the configuration can prove which approved rules they ran.
+/// </summary>
+public class RuleBundleMetadata
+{
+    /// <summary>
+    /// Gets the hash of the bundled configuration JSON.
+    /// </summary>
+    public string Hash { get; init; } = string.Empty;
+
+    /// <summary>
+    /// Gets the outcome of verifying the bundle when it was loaded.
+    /// </summary>
+    [JsonConverter(typeof(JsonStringEnumConverter))]
+    public RuleBundleVerification Verification { get; init; }
+}
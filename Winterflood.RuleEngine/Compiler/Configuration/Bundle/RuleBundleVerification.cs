@@ -0,0 +1,33 @@
+namespace Winterflood.RuleEngine.Compiler.Configuration.Bundle
+{
+    /// <summary>
+    /// Describes the outcome of verifying a <see cref="RuleBundle"/> when it is loaded.
+    /// </summary>
+    public enum RuleBundleVerification
+    {
+        /// <summary>
+        /// The content hash matches and the signature was verified.
+        /// </summary>
+        Verified,
+
+        /// <summary>
+        /// The content hash matches and no verifier was supplied to check the signature.
+        /// </summary>
+        HashVerified,
+
+        /// <summary>
+        /// The configuration does not match the hash recorded in the bundle.
+        /// </summary>
+        HashMismatch,
+
+        /// <summary>
+        /// The bundle is unsigned or its signature was rejected by the verifier.
+        /// </summary>
+        InvalidSignature,
+
+        /// <summary>
+        /// The bundle could not be parsed.
+        /// </summary>
+        Invalid
+    }
+}
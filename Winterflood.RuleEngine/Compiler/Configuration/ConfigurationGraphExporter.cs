@@ -0,0 +1,240 @@
+using System.Text;
+using Winterflood.RuleEngine.Compiler.Configuration.Models;
+using Winterflood.RuleEngine.Engine;
+
+namespace Winterflood.RuleEngine.Compiler.Configuration;
+
+/// <summary>
+/// Renders the rulesets of a rule engine configuration as a graph for visualizing decision flow.
+/// </summary>
+/// <remarks>
+/// Each ruleset is drawn with edges to its rules in evaluation order, nested rules are drawn
+/// with a dashed edge to the ruleset they delegate to, and includes are drawn as dotted edges.
+/// Rules with an error policy that branches are drawn with a red dashed edge to the ruleset evaluated on error,
+/// and rules overriding an included rule with a dotted edge to the rule they replace.
+/// Variants of a ruleset are drawn as separate nodes labelled with their environments, and references
+/// to a ruleset with variants are drawn to each of its variants.
+/// </remarks>
+public static class ConfigurationGraphExporter
+{
+    /// <summary>
+    /// Renders the configuration as a Graphviz DOT graph.
+    /// </summary>
+    /// <param name="configuration">The configuration to render.</param>
+    /// <returns>The DOT source of the graph.</returns>
+    public static string ToDot(RuleEngineConfiguration configuration)
+    {
+        // Variants share a name, so the environments are part of the node id
+        var ruleSetIds = configuration.RuleSets.ToDictionary(ruleSet => ruleSet, ruleSet => Quote(GetRuleSetKey(ruleSet)));
+        var targets = GetReferenceTargets(configuration, ruleSetIds, Quote);
+
+        var builder = new StringBuilder();
+        builder.AppendLine("digraph RuleEngine {");
+        builder.AppendLine("    rankdir=LR;");
+
+        foreach (var ruleSet in configuration.RuleSets)
+        {
+            var ruleSetId = ruleSetIds[ruleSet];
+            builder.AppendLine(
+                $"    {ruleSetId} [shape=box, style=bold, label={Quote(string.Join("\n", GetRuleSetLabel(ruleSet)))}];");
+
+            foreach (var include in ruleSet.Includes)
+            {
+                foreach (var target in targets[include])
+                    builder.AppendLine($"    {ruleSetId} -> {target} [style=dotted, label=\"includes\"];");
+            }
+
+            for (var i = 0; i < ruleSet.Rules.Count; i++)
+            {
+                var rule = ruleSet.Rules[i];
+                var ruleId = GetRuleId(ruleSet, rule);
+
+                builder.AppendLine($"    {ruleId} [shape=ellipse, label={Quote(rule.RuleName)}];");
+                builder.AppendLine($"    {ruleSetId} -> {ruleId} [label=\"{i + 1}\"];");
+
+                if (rule is NestedRuleDefinition nested)
+                {
+                    foreach (var target in targets[nested.RulesetName])
+                        builder.AppendLine($"    {ruleId} -> {target} [style=dashed];");
+                }
+
+                if (GetBranch(rule) is { } branch)
+                {
+                    foreach (var target in targets[branch])
+                        builder.AppendLine($"    {ruleId} -> {target} [style=dashed, color=red, label=\"on error\"];");
+                }
+
+                foreach (var (includedRuleSet, includedRule) in FindOverriddenRules(configuration, ruleSet, rule))
+                    builder.AppendLine($"    {ruleId} -> {GetRuleId(includedRuleSet, includedRule)} [style=dotted, label=\"overrides\"];");
+            }
+        }
+
+        foreach (var missing in FindMissingRuleSets(configuration))
+            builder.AppendLine($"    {Quote(missing)} [shape=box, style=dashed, label={Quote($"{missing}\n(missing)")}];");
+
+        builder.AppendLine("}");
+        return builder.ToString();
+
+        static string GetRuleId(RuleSetDefinition ruleSet, RuleDefinition rule)
+            => Quote($"{GetRuleSetKey(ruleSet)}.{rule.RuleName}");
+
+        static string Quote(string value)
+            => $"\"{value.Replace("\\", "\\\\").Replace("\"", "\\\"").Replace("\n", "\\n")}\"";
+    }
+
+    /// <summary>
+    /// Renders the configuration as a Mermaid flowchart.
+    /// </summary>
+    /// <param name="configuration">The configuration to render.</param>
+    /// <returns>The Mermaid source of the flowchart.</returns>
+    public static string ToMermaid(RuleEngineConfiguration configuration)
+    {
+        // Mermaid node ids must be plain identifiers, so each ruleset, and each variant of one, is mapped to a generated id
+        var ruleSetIds = new Dictionary<RuleSetDefinition, string>();
+        foreach (var ruleSet in configuration.RuleSets)
+            ruleSetIds[ruleSet] = $"rs{ruleSetIds.Count}";
+
+        var missingIds = new Dictionary<string, string>();
+        foreach (var missing in FindMissingRuleSets(configuration))
+            missingIds[missing] = $"rs{ruleSetIds.Count + missingIds.Count}";
+
+        var targets = GetReferenceTargets(configuration, ruleSetIds, missing => missingIds[missing]);
+
+        var builder = new StringBuilder();
+        builder.AppendLine("flowchart LR");
+
+        foreach (var ruleSet in configuration.RuleSets)
+        {
+            var ruleSetId = ruleSetIds[ruleSet];
+            builder.AppendLine($"    {ruleSetId}[\"{string.Join("<br/>", GetRuleSetLabel(ruleSet).Select(Escape))}\"]");
+
+            foreach (var include in ruleSet.Includes)
+            {
+                foreach (var target in targets[include])
+                    builder.AppendLine($"    {ruleSetId} -. includes .-> {target}");
+            }
+
+            for (var i = 0; i < ruleSet.Rules.Count; i++)
+            {
+                var rule = ruleSet.Rules[i];
+                var ruleId = $"{ruleSetId}_r{i}";
+
+                builder.AppendLine($"    {ruleId}([\"{Escape(rule.RuleName)}\"])");
+                builder.AppendLine($"    {ruleSetId} -->|{i + 1}| {ruleId}");
+
+                if (rule is NestedRuleDefinition nested)
+                {
+                    foreach (var target in targets[nested.RulesetName])
+                        builder.AppendLine($"    {ruleId} -.-> {target}");
+                }
+
+                if (GetBranch(rule) is { } branch)
+                {
+                    foreach (var target in targets[branch])
+                        builder.AppendLine($"    {ruleId} -. on error .-> {target}");
+                }
+
+                foreach (var (includedRuleSet, includedRule) in FindOverriddenRules(configuration, ruleSet, rule))
+                    builder.AppendLine($"    {ruleId} -. overrides .-> {ruleSetIds[includedRuleSet]}_r{includedRuleSet.Rules.IndexOf(includedRule)}");
+            }
+        }
+
+        foreach (var (missing, missingId) in missingIds)
+            builder.AppendLine($"    {missingId}[\"{Escape(missing)}<br/>(missing)\"]");
+
+        return builder.ToString();
+
+        static string Escape(string value) => value.Replace("\"", "#quot;");
+    }
+
+    /// <summary>
+    /// Identifies a ruleset, or a variant of one by its environments, within a configuration.
+    /// </summary>
+    private static string GetRuleSetKey(RuleSetDefinition ruleSet)
+        => ruleSet.Environments.Count == 0 ? ruleSet.Name : $"{ruleSet.Name}@{string.Join(",", ruleSet.Environments)}";
+
+    private static IEnumerable<string> GetRuleSetLabel(RuleSetDefinition ruleSet)
+    {
+        yield return ruleSet.Name;
+        yield return ruleSet.ExecutionMode.ToString();
+
+        if (ruleSet.Environments.Count != 0)
+            yield return $"[{string.Join(", ", ruleSet.Environments)}]";
+    }
+
+    private static string? GetBranch(RuleDefinition rule)
+        => rule.ErrorPolicy is { Action: RuleErrorAction.Branch, RuleSetName: { } branch } ? branch : null;
+
+    /// <summary>
+    /// Maps the name of every ruleset that can be referenced to the nodes drawn for it:
+    /// one for each of its variants, or a single missing node when it is not defined.
+    /// </summary>
+    private static Dictionary<string, List<string>> GetReferenceTargets(
+        RuleEngineConfiguration configuration,
+        Dictionary<RuleSetDefinition, string> ruleSetIds,
+        Func<string, string> getMissingId)
+    {
+        var targets = new Dictionary<string, List<string>>();
+
+        foreach (var ruleSet in configuration.RuleSets)
+        {
+            if (!targets.TryGetValue(ruleSet.Name, out var ids))
+                targets[ruleSet.Name] = ids = [];
+
+            ids.Add(ruleSetIds[ruleSet]);
+        }
+
+        foreach (var missing in FindMissingRuleSets(configuration))
+            targets[missing] = [getMissingId(missing)];
+
+        return targets;
+    }
+
+    /// <summary>
+    /// Finds the rules of the rulesets included by <paramref name="ruleSet"/>, directly or indirectly,
+    /// that <paramref name="rule"/> overrides.
+    /// </summary>
+    private static IEnumerable<(RuleSetDefinition RuleSet, RuleDefinition Rule)> FindOverriddenRules(
+        RuleEngineConfiguration configuration,
+        RuleSetDefinition ruleSet,
+        RuleDefinition rule)
+    {
+        if (string.IsNullOrWhiteSpace(rule.Overrides))
+            yield break;
+
+        var visited = new HashSet<string> { ruleSet.Name };
+        var pending = new Queue<string>(ruleSet.Includes);
+
+        while (pending.Count > 0)
+        {
+            var name = pending.Dequeue();
+            if (!visited.Add(name))
+                continue;
+
+            foreach (var included in configuration.RuleSets.Where(r => r.Name == name))
+            {
+                foreach (var includedRule in included.Rules.Where(r => r.RuleName == rule.Overrides))
+                    yield return (included, includedRule);
+
+                foreach (var include in included.Includes)
+                    pending.Enqueue(include);
+            }
+        }
+    }
+
+    /// <summary>
+    /// Finds rulesets referenced by nested rules, includes or error branches that are not defined in the configuration.
+    /// </summary>
+    private static IEnumerable<string> FindMissingRuleSets(RuleEngineConfiguration configuration)
+    {
+        var defined = configuration.RuleSets.Select(r => r.Name).ToHashSet();
+
+        return configuration.RuleSets
+            .SelectMany(r =>
+                r.Rules.OfType<NestedRuleDefinition>().Select(rule => rule.RulesetName)
+                    .Concat(r.Rules.Select(GetBranch).OfType<string>())
+                    .Concat(r.Includes))
+            .Where(name => !defined.Contains(name))
+            .Distinct();
+    }
+}
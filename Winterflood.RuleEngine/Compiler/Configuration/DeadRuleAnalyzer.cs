@@ -0,0 +1,198 @@
+using Winterflood.RuleEngine.Compiler.Configuration.Models;
+using Winterflood.RuleEngine.Engine;
+
+namespace Winterflood.RuleEngine.Compiler.Configuration;
+
+/// <summary>
+/// Finds rules, rulesets and data members of a configuration that can never take effect.
+/// </summary>
+public static class DeadRuleAnalyzer
+{
+    /// <summary>
+    /// Analyzes a configuration for dead rules and broken references.
+    /// </summary>
+    /// <remarks>
+    /// The analysis reports:
+    /// <list type="bullet">
+    /// <item>rules that follow an unconditional rule in a short circuit ruleset and can never run;</item>
+    /// <item>nested rules and error branches referring to rulesets that do not exist;</item>
+    /// <item>includes and overrides that cannot be resolved, such as an include of a missing ruleset or of another data type;</item>
+    /// <item>rulesets that cannot be reached from any of the <paramref name="entryPoints"/>;</item>
+    /// <item>data members that are written by a rule but never read by any rule, and are not one of the <paramref name="outputs"/>.</item>
+    /// </list>
+    /// Members written by rules are usually the results the application reads after the evaluation, so unread members
+    /// are only reported once those outputs are declared.
+    /// </remarks>
+    /// <param name="configuration">The configuration to analyze.</param>
+    /// <param name="entryPoints">
+    /// The names of the rulesets executed directly by the application. When empty, ruleset reachability is not checked.
+    /// </param>
+    /// <param name="outputs">
+    /// The paths of the data members the application reads after the evaluation, such as <c>Discount</c>. A path also
+    /// covers its parent and child members. When empty, unread members are not checked.
+    /// </param>
+    /// <returns>The findings, or an empty list if nothing was found.</returns>
+    public static List<string> Analyze(
+        RuleEngineConfiguration configuration,
+        IEnumerable<string> entryPoints,
+        IEnumerable<string>? outputs = null)
+    {
+        var findings = new List<string>();
+
+        foreach (var ruleSet in configuration.RuleSets)
+        {
+            var rules = FindMissingReferences(configuration, ruleSet, findings);
+            if (rules is not null)
+                FindUnreachableRules(ruleSet, rules, findings);
+        }
+
+        var entryPointNames = entryPoints.ToList();
+        if (entryPointNames.Count > 0)
+            FindUnreachableRuleSets(configuration, entryPointNames, findings);
+
+        var outputPaths = outputs?.ToList() ?? [];
+        if (outputPaths.Count > 0)
+            FindUnreadMembers(configuration, outputPaths, findings);
+
+        return findings;
+    }
+
+    private static void FindUnreachableRules(
+        RuleSetDefinition ruleSet,
+        List<RuleDefinition> rules,
+        List<string> findings)
+    {
+        // A rule that always passes ends a StopOnFirstSuccess ruleset, and one that always fails ends a StopOnFirstFailure ruleset
+        var terminatingCondition = ruleSet.ExecutionMode switch
+        {
+            RuleExecutionMode.StopOnFirstSuccess => "true",
+            RuleExecutionMode.StopOnFirstFailure => "false",
+            _ => null
+        };
+
+        if (terminatingCondition is null)
+            return;
+
+        var terminatingRule = rules.FindIndex(rule =>
+            rule is StandardRuleDefinition standard
+            && standard.Conditions.Trim() == terminatingCondition
+            && standard.Guard is null
+            && standard.ErrorPolicy is null
+            && standard.Adapters.Count == 0);
+
+        if (terminatingRule < 0)
+            return;
+
+        foreach (var rule in rules.Skip(terminatingRule + 1))
+        {
+            findings.Add(
+                $"Rule '{rule.RuleName}' in RuleSet '{ruleSet.Name}' is unreachable " +
+                $"after unconditional Rule '{rules[terminatingRule].RuleName}'.");
+        }
+    }
+
+    /// <summary>
+    /// Reports the references of a ruleset that cannot be resolved, and resolves the rules it evaluates.
+    /// </summary>
+    /// <returns>The rules the ruleset evaluates, or <c>null</c> when its includes or overrides cannot be resolved.</returns>
+    private static List<RuleDefinition>? FindMissingReferences(
+        RuleEngineConfiguration configuration,
+        RuleSetDefinition ruleSet,
+        List<string> findings)
+    {
+        foreach (var rule in ruleSet.Rules)
+        {
+            if (rule is NestedRuleDefinition nested && !IsDefined(configuration, nested.RulesetName))
+                findings.Add($"Rule '{rule.RuleName}' in RuleSet '{ruleSet.Name}' references undefined RuleSet '{nested.RulesetName}'.");
+
+            if (rule.ErrorPolicy?.RuleSetName is { } branch && !IsDefined(configuration, branch))
+                findings.Add($"Rule '{rule.RuleName}' in RuleSet '{ruleSet.Name}' branches to undefined RuleSet '{branch}'.");
+        }
+
+        // Reports missing includes, includes of another data type, cycles, unknown overrides and clashing names
+        try
+        {
+            return RuleSetIncludeResolver.ResolveRules(configuration, ruleSet);
+        }
+        catch (InvalidOperationException e)
+        {
+            findings.Add(e.Message);
+            return null;
+        }
+    }
+
+    private static void FindUnreachableRuleSets(
+        RuleEngineConfiguration configuration,
+        List<string> entryPoints,
+        List<string> findings)
+    {
+        var reachable = new HashSet<string>();
+        var pending = new Stack<string>(entryPoints);
+
+        while (pending.Count > 0)
+        {
+            var name = pending.Pop();
+            if (!reachable.Add(name))
+                continue;
+
+            foreach (var ruleSet in configuration.RuleSets.Where(r => r.Name == name))
+            {
+                foreach (var reference in GetReferences(ruleSet))
+                    pending.Push(reference);
+            }
+        }
+
+        foreach (var name in configuration.RuleSets.Select(r => r.Name).Distinct().Where(n => !reachable.Contains(n)))
+            findings.Add($"RuleSet '{name}' is never referenced and is not an entry point.");
+    }
+
+    private static void FindUnreadMembers(
+        RuleEngineConfiguration configuration,
+        List<string> outputs,
+        List<string> findings)
+    {
+        var graph = RuleDependencyAnalyzer.Analyze(configuration);
+
+        // Rules of nested and branch rulesets use paths from other data, and are already covered by the rule evaluating them
+        var evaluatedRuleSets =
+            graph.Rules
+                .SelectMany(rule => new[] { rule.NestedRuleSet, rule.BranchRuleSet })
+                .OfType<string>()
+                .ToHashSet();
+
+        var rules = graph.Rules.Where(rule => !evaluatedRuleSets.Contains(rule.RuleSetName)).ToList();
+        var reported = new HashSet<string>();
+
+        // Inherited rules are reported once, under the ruleset declaring them
+        foreach (var rule in rules.Where(rule => rule.IncludedFrom is null))
+        {
+            foreach (var written in rule.Writes)
+            {
+                var isRead =
+                    outputs.Any(output => RuleDependencyGraph.Overlaps(output, written))
+                    || rules.Any(other => other.Reads.Any(read => RuleDependencyGraph.Overlaps(read, written)));
+
+                if (!isRead && reported.Add(written))
+                    findings.Add($"Member '{written}' is written by Rule '{rule.RuleName}' in RuleSet '{rule.RuleSetName}' but never read.");
+            }
+        }
+    }
+
+    private static IEnumerable<string> GetReferences(RuleSetDefinition ruleSet)
+    {
+        foreach (var include in ruleSet.Includes)
+            yield return include;
+
+        foreach (var rule in ruleSet.Rules)
+        {
+            if (rule is NestedRuleDefinition nested)
+                yield return nested.RulesetName;
+
+            if (rule.ErrorPolicy?.RuleSetName is { } branch)
+                yield return branch;
+        }
+    }
+
+    private static bool IsDefined(RuleEngineConfiguration configuration, string name)
+        => configuration.RuleSets.Any(r => r.Name == name);
+}
@@ -1,4 +1,5 @@
 using System.Text.Json;
+using System.Text.Json.Nodes;
 using System.Text.Json.Serialization;
 using Microsoft.Extensions.Logging;
 using Winterflood.RuleEngine.Compiler.Configuration.Models;
@@ -58,6 +59,7 @@ public class JsonRuleDefinitionConverter : JsonConverter<RuleDefinition>
             {
                 RuleType.StandardRule => Deserialize<StandardRuleDefinition>(root, options),
                 RuleType.NestedRuleSet => Deserialize<NestedRuleDefinition>(root, options),
+                RuleType.ScoreCard => Deserialize<ScoreCardRuleDefinition>(root, options),
                 _ => throw new NotImplementedException($"Unsupported rule type: {ruleType}")
             };
 
@@ -97,12 +99,17 @@ public class JsonRuleDefinitionConverter : JsonConverter<RuleDefinition>
             {
                 case StandardRuleDefinition standardRule:
                     _logger.LogInformation("Serializing StandardRuleDefinition: RuleName={RuleName}", standardRule.RuleName);
-                    JsonSerializer.Serialize(writer, standardRule, options);
+                    WriteWithType(writer, standardRule, RuleType.StandardRule, options);
                     break;
 
                 case NestedRuleDefinition nestedRule:
                     _logger.LogInformation("Serializing NestedRuleSetRuleDefinition: RuleName={RuleName}", nestedRule.RuleName);
-                    JsonSerializer.Serialize(writer, nestedRule, options);
+                    WriteWithType(writer, nestedRule, RuleType.NestedRuleSet, options);
+                    break;
+
+                case ScoreCardRuleDefinition scoreCardRule:
+                    _logger.LogInformation("Serializing ScoreCardRuleDefinition: RuleName={RuleName}", scoreCardRule.RuleName);
+                    WriteWithType(writer, scoreCardRule, RuleType.ScoreCard, options);
                     break;
 
                 default:
@@ -117,6 +124,25 @@ public class JsonRuleDefinitionConverter : JsonConverter<RuleDefinition>
         }
     }
 
+    /// <summary>
+    /// Serializes a RuleDefinition subclass along with the 'type' discriminator required by <see cref="Read"/>.
+    /// </summary>
+    /// <typeparam name="T">The RuleDefinition subclass type.</typeparam>
+    /// <param name="writer">The JSON writer.</param>
+    /// <param name="value">The rule to serialize.</param>
+    /// <param name="ruleType">The discriminator value written to the 'type' property.</param>
+    /// <param name="options">Serialization options.</param>
+    private static void WriteWithType<T>(
+        Utf8JsonWriter writer,
+        T value,
+        RuleType ruleType,
+        JsonSerializerOptions options) where T : RuleDefinition
+    {
+        var node = JsonSerializer.SerializeToNode(value, options)!.AsObject();
+        node["type"] = ruleType.ToString();
+        node.WriteTo(writer, options);
+    }
+
     /// <summary>
     /// Safely deserializes a JSON element into a specific RuleDefinition subclass.
     /// </summary>
@@ -16,7 +16,11 @@ public enum RuleType
     /// <summary>
     /// 
     /// </summary>
-    NestedRuleSet
+    NestedRuleSet,
+    /// <summary>
+    /// A weighted scorecard that passes when the score of its factors reaches a threshold.
+    /// </summary>
+    ScoreCard
 }
 /// <summary>
 /// Represents a definition of a ruleset, including its metadata, rules, and associated test cases.
@@ -38,6 +42,18 @@ public class RuleSetDefinition
     /// </summary>
     public RuleExecutionMode ExecutionMode { get; set; } = RuleExecutionMode.All;
 
+    /// <summary>
+    /// Gets or sets the names of rulesets whose rules are evaluated before this ruleset's own rules.
+    /// Included rulesets must operate on the same data type.
+    /// </summary>
+    public List<string> Includes { get; set; } = [];
+
+    /// <summary>
+    /// Gets or sets the environments this ruleset variant applies to.
+    /// An empty list applies the ruleset to every environment that has no variant of its own.
+    /// </summary>
+    public List<string> Environments { get; set; } = [];
+
     /// <summary>
     /// Gets or sets the list of rules that this ruleset includes.
     /// </summary>
@@ -68,6 +84,22 @@ public abstract class RuleDefinition
     /// Gets or sets the optional binding logic used if the Bind adapter is applied.
     /// </summary>
     public BindingAdapter? Binding { get; set; }
+
+    /// <summary>
+    /// Gets or sets the name of an included rule that this rule replaces, keeping the included rule's position.
+    /// </summary>
+    public string? Overrides { get; set; }
+
+    /// <summary>
+    /// Gets or sets an optional condition expression, over <c>data</c> and <c>ctx</c>, that must evaluate
+    /// to true for the rule to be evaluated. Rules whose guard does not hold are skipped and recorded as skipped.
+    /// </summary>
+    public string? Guard { get; set; }
+
+    /// <summary>
+    /// Gets or sets the optional policy applied when evaluating the rule raises an error.
+    /// </summary>
+    public ErrorPolicyDefinition? ErrorPolicy { get; set; }
 }
 
 /// <summary>
@@ -107,6 +139,59 @@ public class NestedRuleDefinition : RuleDefinition
     public string DataType { get; set; } = "string";
 }
 
+/// <summary>
+/// Represents a weighted scorecard rule whose factors add their weight to a score when their condition holds.
+/// </summary>
+public class ScoreCardRuleDefinition : RuleDefinition
+{
+    /// <summary>
+    /// Gets or sets the score required for the scorecard to pass.
+    /// </summary>
+    public decimal Threshold { get; set; }
+
+    /// <summary>
+    /// Gets or sets the factors that contribute to the score.
+    /// </summary>
+    public List<ScoreCardFactorDefinition> Factors { get; set; } = [];
+
+    /// <summary>
+    /// Gets or sets the data member the score is assigned to, such as <c>CreditScore</c> or <c>Customer.Score</c>.
+    /// The member must be assignable from <see cref="decimal"/>. The score is not assigned when this is empty.
+    /// </summary>
+    public string ScoreMember { get; set; } = string.Empty;
+
+    /// <summary>
+    /// Gets or sets the code to execute when the score reaches the threshold.
+    /// </summary>
+    public string OnSuccess { get; set; } = string.Empty;
+
+    /// <summary>
+    /// Gets or sets the code to execute when the score falls short of the threshold.
+    /// </summary>
+    public string OnFailure { get; set; } = string.Empty;
+}
+
+/// <summary>
+/// Represents a single weighted condition of a scorecard.
+/// </summary>
+public class ScoreCardFactorDefinition
+{
+    /// <summary>
+    /// Gets or sets the name of the factor, used in the score breakdown.
+    /// </summary>
+    public string Name { get; set; } = string.Empty;
+
+    /// <summary>
+    /// Gets or sets the condition expression that must evaluate to true for the factor to contribute its weight.
+    /// </summary>
+    public string Condition { get; set; } = "false";
+
+    /// <summary>
+    /// Gets or sets the points the factor contributes when its condition holds.
+    /// </summary>
+    public decimal Weight { get; set; }
+}
+
 /// <summary>
 /// Represents optional binding logic applied to a rule or ruleset when using the Bind adapter.
 /// </summary>
@@ -131,3 +216,24 @@ public class BindingAdapter
     /// </summary>
     public string? AfterExecute { get; set; }
 }
+
+/// <summary>
+/// Represents how a rule handles errors raised while it is evaluated.
+/// </summary>
+public class ErrorPolicyDefinition
+{
+    /// <summary>
+    /// Gets or sets the action taken when the rule raises an error.
+    /// </summary>
+    public RuleErrorAction Action { get; set; } = RuleErrorAction.Fail;
+
+    /// <summary>
+    /// Gets or sets the number of additional attempts made when the action is Retry.
+    /// </summary>
+    public int RetryCount { get; set; }
+
+    /// <summary>
+    /// Gets or sets the name of the ruleset evaluated in place of the rule when the action is Branch.
+    /// </summary>
+    public string? RuleSetName { get; set; }
+}
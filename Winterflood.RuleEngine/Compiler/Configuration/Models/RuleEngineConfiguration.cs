@@ -1,3 +1,6 @@
+using System.Text.Json.Serialization;
+using Winterflood.RuleEngine.Compiler.Configuration.Bundle;
+
 namespace Winterflood.RuleEngine.Compiler.Configuration.Models;
 
 /// <summary>
@@ -7,4 +10,20 @@ public class RuleEngineConfiguration
 {
     public List<DataModelDefinition> Types { get; set; } = [];
     public List<RuleSetDefinition> RuleSets { get; set; } = [];
+
+    /// <summary>
+    /// Gets or sets the names of the environments, such as dev or prod, that every ruleset must be available in.
+    /// </summary>
+    public List<string> Environments { get; set; } = [];
+
+    /// <summary>
+    /// Gets or sets the bundle the configuration was loaded from and how it was verified,
+    /// or <c>null</c> if it was not loaded from a bundle.
+    /// </summary>
+    /// <remarks>
+    /// Set by <see cref="RuleBundleLoader.LoadBundle"/>. It describes where the configuration came from rather than
+    /// what it contains, so it is not serialized and does not affect the configuration hash.
+    /// </remarks>
+    [JsonIgnore]
+    public RuleBundleMetadata? Bundle { get; set; }
 }
\ No newline at end of file
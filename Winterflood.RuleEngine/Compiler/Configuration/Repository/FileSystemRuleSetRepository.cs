@@ -0,0 +1,147 @@
+using System.Text.Json;
+using Microsoft.Extensions.Logging;
+using Winterflood.RuleEngine.Compiler.Configuration.Models;
+
+namespace Winterflood.RuleEngine.Compiler.Configuration.Repository;
+
+/// <summary>
+/// Stores ruleset definitions as JSON files in a directory, one file per ruleset named <c>{RuleSetName}.json</c>.
+/// A file whose ruleset has a different name than the file is not loaded.
+/// </summary>
+public class FileSystemRuleSetRepository : IRuleSetRepository
+{
+    private const string FileExtension = ".json";
+
+    private readonly string _directory;
+    private readonly ILogger<FileSystemRuleSetRepository> _logger;
+    private readonly JsonSerializerOptions _options;
+
+    /// <summary>
+    /// Initializes a new instance of the <see cref="FileSystemRuleSetRepository"/> class.
+    /// </summary>
+    /// <param name="directory">The directory containing the ruleset files. Created if it does not exist.</param>
+    /// <param name="loggerFactory">Logger factory for structured logging.</param>
+    /// <exception cref="ArgumentNullException">Thrown when <paramref name="directory"/> is null.</exception>
+    public FileSystemRuleSetRepository(string directory, ILoggerFactory loggerFactory)
+    {
+        _directory = directory ?? throw new ArgumentNullException(nameof(directory));
+        _logger = loggerFactory.CreateLogger<FileSystemRuleSetRepository>();
+        _options = RuleDefinitionParser.CreateSerializerOptions(loggerFactory);
+
+        Directory.CreateDirectory(_directory);
+    }
+
+    /// <inheritdoc />
+    public RuleSetDefinition? LoadRuleSet(string name)
+    {
+        var path = GetPath(name);
+
+        if (!File.Exists(path))
+        {
+            _logger.LogWarning("RuleSet not found: RuleSet={RuleSetName}, Path={Path}", name, path);
+            return null;
+        }
+
+        try
+        {
+            var ruleSet = JsonSerializer.Deserialize<RuleSetDefinition>(File.ReadAllText(path), _options);
+
+            if (ruleSet == null)
+            {
+                _logger.LogError("Failed to load RuleSet={RuleSetName}: Deserialized definition is null.", name);
+                return null;
+            }
+
+            // A renamed or copied file would otherwise be loaded under a name its ruleset does not have
+            if (ruleSet.Name != name)
+            {
+                _logger.LogError(
+                    "Failed to load RuleSet={RuleSetName} from Path={Path}: The file defines RuleSet={DefinedName}.",
+                    name,
+                    path,
+                    ruleSet.Name);
+                return null;
+            }
+
+            _logger.LogInformation("Loaded RuleSet={RuleSetName} from Path={Path}", name, path);
+            return ruleSet;
+        }
+        catch (Exception ex)
+        {
+            _logger.LogError(ex, "Failed to load RuleSet={RuleSetName} from Path={Path}", name, path);
+            return null;
+        }
+    }
+
+    /// <inheritdoc />
+    public void SaveRuleSet(RuleSetDefinition ruleSet)
+    {
+        ArgumentNullException.ThrowIfNull(ruleSet);
+
+        var path = GetPath(ruleSet.Name);
+
+        try
+        {
+            File.WriteAllText(path, JsonSerializer.Serialize(ruleSet, _options));
+            _logger.LogInformation("Saved RuleSet={RuleSetName} to Path={Path}", ruleSet.Name, path);
+        }
+        catch (Exception ex)
+        {
+            _logger.LogError(ex, "Failed to save RuleSet={RuleSetName} to Path={Path}", ruleSet.Name, path);
+            throw;
+        }
+    }
+
+    /// <inheritdoc />
+    public IReadOnlyList<string> ListRuleSets()
+    {
+        return Directory
+            .EnumerateFiles(_directory, $"*{FileExtension}")
+            .Select(Path.GetFileNameWithoutExtension)
+            .OfType<string>()
+            .OrderBy(name => name, StringComparer.Ordinal)
+            .ToList();
+    }
+
+    /// <inheritdoc />
+    public IDisposable Watch(Action<string> onChanged)
+    {
+        ArgumentNullException.ThrowIfNull(onChanged);
+
+        var watcher = new FileSystemWatcher(_directory, $"*{FileExtension}")
+        {
+            NotifyFilter = NotifyFilters.FileName | NotifyFilters.LastWrite
+        };
+
+        watcher.Changed += (_, e) => Notify(e.FullPath);
+        watcher.Created += (_, e) => Notify(e.FullPath);
+        watcher.Deleted += (_, e) => Notify(e.FullPath);
+        watcher.Renamed += (_, e) =>
+        {
+            Notify(e.OldFullPath);
+            Notify(e.FullPath);
+        };
+        watcher.EnableRaisingEvents = true;
+
+        _logger.LogInformation("Watching RuleSet repository Directory={Directory}", _directory);
+        return watcher;
+
+        void Notify(string path)
+        {
+            var name = Path.GetFileNameWithoutExtension(path);
+            _logger.LogInformation("RuleSet changed: RuleSet={RuleSetName}", name);
+            onChanged(name);
+        }
+    }
+
+    /// <summary>
+    /// Resolves the file path of a ruleset, rejecting names that would escape the repository directory.
+    /// </summary>
+    private string GetPath(string name)
+    {
+        if (string.IsNullOrWhiteSpace(name) || name.IndexOfAny(Path.GetInvalidFileNameChars()) >= 0 || name is "." or "..")
+            throw new ArgumentException($"Invalid ruleset name: '{name}'", nameof(name));
+
+        return Path.Combine(_directory, name + FileExtension);
+    }
+}
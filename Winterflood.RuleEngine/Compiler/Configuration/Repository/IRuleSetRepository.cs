@@ -0,0 +1,35 @@
+using Winterflood.RuleEngine.Compiler.Configuration.Models;
+
+namespace Winterflood.RuleEngine.Compiler.Configuration.Repository;
+
+/// <summary>
+/// Defines a persistent store of <see cref="RuleSetDefinition"/> instances.
+/// </summary>
+public interface IRuleSetRepository
+{
+    /// <summary>
+    /// Loads a ruleset definition by name.
+    /// </summary>
+    /// <param name="name">The name of the ruleset to load.</param>
+    /// <returns>The ruleset definition if found and valid; otherwise, <c>null</c>.</returns>
+    RuleSetDefinition? LoadRuleSet(string name);
+
+    /// <summary>
+    /// Saves a ruleset definition, replacing any existing ruleset with the same name.
+    /// </summary>
+    /// <param name="ruleSet">The ruleset definition to save.</param>
+    void SaveRuleSet(RuleSetDefinition ruleSet);
+
+    /// <summary>
+    /// Lists the names of all rulesets available in the repository.
+    /// </summary>
+    /// <returns>The ruleset names.</returns>
+    IReadOnlyList<string> ListRuleSets();
+
+    /// <summary>
+    /// Watches the repository for changes.
+    /// </summary>
+    /// <param name="onChanged">Callback invoked with the name of a ruleset when it is created, changed or removed.</param>
+    /// <returns>A handle that stops watching when disposed.</returns>
+    IDisposable Watch(Action<string> onChanged);
+}
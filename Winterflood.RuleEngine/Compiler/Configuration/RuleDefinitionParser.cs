@@ -1,5 +1,8 @@
+using System.Security.Cryptography;
+using System.Text;
 using System.Text.Json;
 using System.Text.Json.Serialization;
+using System.Text.RegularExpressions;
 using Microsoft.Extensions.Logging;
 using Winterflood.RuleEngine.Compiler.Configuration.Models;
 
@@ -26,12 +29,7 @@ public static class RuleDefinitionParser
             return null;
         }
 
-        var options = new JsonSerializerOptions
-        {
-            PropertyNameCaseInsensitive = true,
-            WriteIndented = true,
-            Converters = { new JsonRuleDefinitionConverter(loggerFactory), new JsonStringEnumConverter() }
-        };
+        var options = CreateSerializerOptions(loggerFactory);
 
         try
         {
@@ -58,4 +56,72 @@ public static class RuleDefinitionParser
             return null;
         }
     }
+
+    /// <summary>
+    /// Merges several configurations, such as one per file, into a single configuration.
+    /// </summary>
+    /// <param name="configurations">The configurations to merge, in order.</param>
+    /// <returns>A configuration containing the types, rulesets and environments of every configuration.</returns>
+    public static RuleEngineConfiguration MergeConfigurations(IEnumerable<RuleEngineConfiguration> configurations)
+    {
+        var merged = new RuleEngineConfiguration();
+
+        foreach (var configuration in configurations)
+        {
+            merged.Types.AddRange(configuration.Types);
+            merged.RuleSets.AddRange(configuration.RuleSets);
+            merged.Environments.AddRange(configuration.Environments.Except(merged.Environments));
+        }
+
+        return merged;
+    }
+
+    /// <summary>
+    /// Creates the JSON serializer options used to read and write rule engine configuration.
+    /// </summary>
+    /// <param name="loggerFactory">Factory for structured logging.</param>
+    /// <returns>Serializer options that understand polymorphic <see cref="RuleDefinition"/> instances.</returns>
+    public static JsonSerializerOptions CreateSerializerOptions(ILoggerFactory loggerFactory)
+    {
+        return new JsonSerializerOptions
+        {
+            PropertyNameCaseInsensitive = true,
+            WriteIndented = true,
+            Converters = { new JsonRuleDefinitionConverter(loggerFactory), new JsonStringEnumConverter() }
+        };
+    }
+
+    /// <summary>
+    /// Computes a hash identifying the content of a rule engine configuration.
+    /// </summary>
+    /// <remarks>
+    /// Names generated for types, fields and rules that were not given one differ every time a configuration
+    /// is loaded, so they are hashed by the order they first appear in rather than by their value. Generated
+    /// ruleset names are hashed as they are, because compiled rulesets are looked up by their name.
+    /// </remarks>
+    /// <param name="configuration">The configuration to hash.</param>
+    /// <param name="loggerFactory">Factory for structured logging.</param>
+    /// <returns>The hex encoded SHA-256 hash of the serialized configuration.</returns>
+    public static string ComputeHash(RuleEngineConfiguration configuration, ILoggerFactory loggerFactory)
+    {
+        var json = JsonSerializer.Serialize(configuration, CreateSerializerOptions(loggerFactory));
+
+        var generatedNames = new Dictionary<string, int>();
+        json = GeneratedNamePattern.Replace(json, match =>
+        {
+            if (!generatedNames.TryGetValue(match.Value, out var index))
+                generatedNames[match.Value] = index = generatedNames.Count;
+
+            return $"\"_{match.Groups["kind"].Value}_{index}\"";
+        });
+
+        return Convert.ToHexString(SHA256.HashData(Encoding.UTF8.GetBytes(json)));
+    }
+
+    /// <summary>
+    /// Matches the default names of <see cref="DataModelDefinition"/>, <see cref="FieldDefinition"/> and <see cref="RuleDefinition"/>.
+    /// </summary>
+    private static readonly Regex GeneratedNamePattern = new(
+        "\"_(?<kind>data_model|field|rule)_[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}\"",
+        RegexOptions.Compiled);
 }
@@ -0,0 +1,416 @@
+using Microsoft.CodeAnalysis;
+using Microsoft.CodeAnalysis.CSharp;
+using Microsoft.CodeAnalysis.CSharp.Syntax;
+using Winterflood.RuleEngine.Compiler.Configuration.Models;
+
+namespace Winterflood.RuleEngine.Compiler.Configuration;
+
+/// <summary>
+/// Builds a <see cref="RuleDependencyGraph"/> by analyzing the C# expressions of each rule definition.
+/// </summary>
+/// <remarks>
+/// Only members accessed directly from the <c>data</c> parameter, or the <c>sourceData</c> and <c>targetData</c>
+/// parameters of a binding, are tracked; members reached through lambda parameters, such as <c>i.Price</c> in
+/// <c>data.Items.Any(i =&gt; i.Price &gt; 10)</c>, are not.
+/// Calls to methods of the data, such as <c>data.Items.Add(item)</c>, are recorded as reads of the member.
+/// Calls are only recorded for functions called by name or through a type, such as <c>Math.Round(data.Total)</c>;
+/// methods called on variables declared by the rule or on lambda parameters are not, and since expressions are
+/// analyzed without resolving types, a member accessed through an unknown identifier is assumed to be a type.
+/// A rule bound to a member of the data, such as <c>return sourceData.Customer</c>, reports the members it uses
+/// from that member, and a nested rule or error branch also reports the members used by the rulesets it evaluates.
+/// Where rulesets nest each other, the rulesets already being analyzed add nothing further to the rules that reach them again.
+/// Members of data created by a binding cannot be related to the ruleset's data and are not reported.
+/// </remarks>
+public static class RuleDependencyAnalyzer
+{
+    private const string DataParameter = "data";
+    private const string ContextParameter = "ctx";
+    private const string SourceParameter = "sourceData";
+    private const string TargetParameter = "targetData";
+
+    private static readonly string[] Parameters = [DataParameter, ContextParameter, SourceParameter, TargetParameter];
+
+    /// <summary>
+    /// Analyzes the rules of every ruleset in the configuration, including the rules each ruleset inherits
+    /// through <see cref="RuleSetDefinition.Includes"/>.
+    /// </summary>
+    /// <param name="configuration">The configuration to analyze.</param>
+    /// <returns>The dependency graph of the configuration's rules.</returns>
+    public static RuleDependencyGraph Analyze(RuleEngineConfiguration configuration)
+    {
+        var analyzed = new Dictionary<RuleSetDefinition, List<RuleDependency>>();
+
+        // The rulesets being analyzed, with their depth in the chain of nested rulesets that reached them
+        var analyzing = new Dictionary<RuleSetDefinition, int>();
+
+        // The shallowest ruleset being analyzed that the current analysis reached again
+        var shallowestReached = int.MaxValue;
+
+        return new RuleDependencyGraph(configuration.RuleSets.SelectMany(AnalyzeRuleSet).ToList());
+
+        List<RuleDependency> AnalyzeRuleSet(RuleSetDefinition ruleSet)
+        {
+            if (analyzed.TryGetValue(ruleSet, out var existing))
+                return existing;
+
+            // A ruleset that nests itself adds nothing further while it is being analyzed
+            if (analyzing.TryGetValue(ruleSet, out var reachedDepth))
+            {
+                shallowestReached = Math.Min(shallowestReached, reachedDepth);
+                return [];
+            }
+
+            var depth = analyzing.Count;
+            analyzing[ruleSet] = depth;
+
+            var outerReached = shallowestReached;
+            shallowestReached = int.MaxValue;
+
+            var dependencies = GetRules(configuration, ruleSet).Select(rule => AnalyzeRule(ruleSet, rule)).ToList();
+
+            analyzing.Remove(ruleSet);
+
+            // Dependencies that reached a ruleset still being analyzed above this one lack that ruleset's members,
+            // so they are only cached when this ruleset is reached without the cycle open
+            if (shallowestReached >= depth)
+                analyzed[ruleSet] = dependencies;
+
+            shallowestReached = Math.Min(outerReached, shallowestReached < depth ? shallowestReached : int.MaxValue);
+            return dependencies;
+        }
+
+        RuleDependency AnalyzeRule(RuleSetDefinition ruleSet, RuleDefinition rule)
+        {
+            var dependency = new RuleDependency
+            {
+                RuleSetName = ruleSet.Name,
+                RuleName = rule.RuleName,
+                IncludedFrom = ruleSet.Rules.Contains(rule)
+                    ? null
+                    : configuration.RuleSets.FirstOrDefault(r => r.Rules.Contains(rule))?.Name
+            };
+
+            // The guard and the error policy wrap the binding, so they see the ruleset's data
+            var outerScope = new Dictionary<string, string> { [DataParameter] = string.Empty };
+
+            if (rule.Guard is not null)
+                AnalyzeExpression(rule.Guard, dependency, outerScope);
+
+            var boundPath = AnalyzeBinding(rule, dependency);
+            if (boundPath is not null && rule.Adapters.Contains("ForCollection"))
+                boundPath = JoinPath(boundPath, "[]");
+
+            var scope = new Dictionary<string, string>();
+            if (boundPath is not null)
+                scope[DataParameter] = boundPath;
+
+            switch (rule)
+            {
+                case StandardRuleDefinition standard:
+                    AnalyzeExpression(standard.Conditions, dependency, scope);
+                    AnalyzeStatements(standard.OnSuccess, dependency, scope);
+                    AnalyzeStatements(standard.OnFailure, dependency, scope);
+                    break;
+
+                case ScoreCardRuleDefinition scoreCard:
+                    foreach (var factor in scoreCard.Factors)
+                        AnalyzeExpression(factor.Condition, dependency, scope);
+
+                    if (boundPath is not null && !string.IsNullOrWhiteSpace(scoreCard.ScoreMember))
+                        dependency.Writes.Add(JoinPath(boundPath, scoreCard.ScoreMember.Trim()));
+
+                    AnalyzeStatements(scoreCard.OnSuccess, dependency, scope);
+                    AnalyzeStatements(scoreCard.OnFailure, dependency, scope);
+                    break;
+
+                case NestedRuleDefinition nested:
+                    dependency.NestedRuleSet = nested.RulesetName;
+                    AddRuleSetDependencies(nested.RulesetName, boundPath);
+                    break;
+            }
+
+            if (rule.ErrorPolicy?.RuleSetName is { } branch)
+            {
+                dependency.BranchRuleSet = branch;
+                AddRuleSetDependencies(branch, string.Empty);
+            }
+
+            return dependency;
+
+            void AddRuleSetDependencies(string ruleSetName, string? path)
+            {
+                var target = configuration.RuleSets.FirstOrDefault(r => r.Name == ruleSetName);
+                if (target is null)
+                    return;
+
+                foreach (var nestedDependency in AnalyzeRuleSet(target))
+                {
+                    dependency.Calls.UnionWith(nestedDependency.Calls);
+
+                    if (path is null)
+                        continue;
+
+                    dependency.Reads.UnionWith(nestedDependency.Reads.Select(read => JoinPath(path, read)));
+                    dependency.Writes.UnionWith(nestedDependency.Writes.Select(written => JoinPath(path, written)));
+                }
+            }
+        }
+    }
+
+    /// <summary>
+    /// Returns the rules a ruleset evaluates, with included rules first, as the compiler resolves them.
+    /// </summary>
+    private static List<RuleDefinition> GetRules(RuleEngineConfiguration configuration, RuleSetDefinition ruleSet)
+    {
+        try
+        {
+            return RuleSetIncludeResolver.ResolveRules(configuration, ruleSet);
+        }
+        catch (InvalidOperationException)
+        {
+            // Broken includes are reported by DeadRuleAnalyzer; the ruleset's own rules can still be analyzed
+            return ruleSet.Rules;
+        }
+    }
+
+    /// <summary>
+    /// Analyzes the binding of a rule and returns the path of the data the rule is bound to,
+    /// or <c>null</c> if the binding creates data that cannot be related to the ruleset's data.
+    /// </summary>
+    private static string? AnalyzeBinding(RuleDefinition rule, RuleDependency dependency)
+    {
+        if (!rule.Adapters.Contains("Bind") || rule.Binding is null)
+            return string.Empty;
+
+        var sourceScope = new Dictionary<string, string> { [SourceParameter] = string.Empty };
+
+        // The factory and the after execute action are parsed the same way the compiler parses them
+        var factory = SyntaxFactory.ParseStatement(rule.Binding.BindFactory + ";");
+        Analyze(factory, dependency, sourceScope, GetDeclaredNames([factory]));
+
+        var boundPath =
+            factory is ReturnStatementSyntax { Expression: { } returned }
+                ? GetDataPath(returned, sourceScope)
+                : null;
+
+        if (!string.IsNullOrWhiteSpace(rule.Binding.AfterExecute))
+        {
+            var scope = new Dictionary<string, string>(sourceScope);
+            if (boundPath is not null)
+                scope[TargetParameter] = boundPath;
+
+            var afterExecute = SyntaxFactory.ParseStatement(rule.Binding.AfterExecute);
+            Analyze(afterExecute, dependency, scope, GetDeclaredNames([afterExecute]));
+        }
+
+        return boundPath;
+    }
+
+    private static void AnalyzeExpression(string expression, RuleDependency dependency, Dictionary<string, string> scope)
+    {
+        if (string.IsNullOrWhiteSpace(expression))
+            return;
+
+        var parsed = SyntaxFactory.ParseExpression(expression);
+        Analyze(parsed, dependency, scope, GetDeclaredNames([parsed]));
+    }
+
+    private static void AnalyzeStatements(string statements, RuleDependency dependency, Dictionary<string, string> scope)
+    {
+        // Statements are split the same way the compiler splits them
+        var parsed =
+            statements.Split(';')
+                .Where(line => !string.IsNullOrWhiteSpace(line))
+                .Select(statement => SyntaxFactory.ParseStatement(statement.Trim() + ";"))
+                .ToList();
+
+        // A variable declared by one statement can be used by the statements after it
+        var declared = GetDeclaredNames(parsed);
+        foreach (var statement in parsed)
+            Analyze(statement, dependency, scope, declared);
+    }
+
+    /// <summary>
+    /// Returns the names of the variables and lambda parameters declared within the given syntax nodes.
+    /// </summary>
+    private static HashSet<string> GetDeclaredNames(IEnumerable<SyntaxNode> roots)
+        => roots
+            .SelectMany(root => root.DescendantNodesAndSelf())
+            .Select(node => node switch
+            {
+                ParameterSyntax parameter => parameter.Identifier.Text,
+                VariableDeclaratorSyntax variable => variable.Identifier.Text,
+                ForEachStatementSyntax forEach => forEach.Identifier.Text,
+                SingleVariableDesignationSyntax designation => designation.Identifier.Text,
+                _ => null
+            })
+            .OfType<string>()
+            .ToHashSet();
+
+    /// <summary>
+    /// Records the members, context keys and functions used by a syntax node.
+    /// </summary>
+    /// <param name="root">The expression or statement to analyze.</param>
+    /// <param name="dependency">The dependency to record into.</param>
+    /// <param name="scope">The paths, relative to the ruleset's data, of the parameters whose members are tracked.</param>
+    /// <param name="declared">The variables and lambda parameters declared by the rule, whose methods are not recorded as calls.</param>
+    private static void Analyze(
+        SyntaxNode root,
+        RuleDependency dependency,
+        Dictionary<string, string> scope,
+        HashSet<string> declared)
+    {
+        foreach (var node in root.DescendantNodesAndSelf())
+        {
+            switch (node)
+            {
+                case AssignmentExpressionSyntax assignment when GetDataPath(assignment.Left, scope) is { } written:
+                    dependency.Writes.Add(written);
+
+                    // Compound assignments such as += also read the member
+                    if (!assignment.IsKind(SyntaxKind.SimpleAssignmentExpression))
+                        dependency.Reads.Add(written);
+                    break;
+
+                case PostfixUnaryExpressionSyntax postfix when GetDataPath(postfix.Operand, scope) is { } incremented:
+                    dependency.Reads.Add(incremented);
+                    dependency.Writes.Add(incremented);
+                    break;
+
+                case PrefixUnaryExpressionSyntax prefix
+                    when (prefix.IsKind(SyntaxKind.PreIncrementExpression) || prefix.IsKind(SyntaxKind.PreDecrementExpression))
+                         && GetDataPath(prefix.Operand, scope) is { } incremented:
+                    dependency.Reads.Add(incremented);
+                    dependency.Writes.Add(incremented);
+                    break;
+
+                case InvocationExpressionSyntax
+                {
+                    Expression: MemberAccessExpressionSyntax
+                    {
+                        Expression: IdentifierNameSyntax { Identifier.Text: ContextParameter }
+                    } contextCall
+                } invocation:
+                    AnalyzeContextCall(contextCall.Name, invocation.ArgumentList, dependency);
+                    break;
+
+                case InvocationExpressionSyntax invocation:
+                    var callee = GetRootIdentifier(invocation.Expression);
+                    if (callee is not null
+                        && !Parameters.Contains(callee)
+                        && !declared.Contains(callee))
+                        dependency.Calls.Add(invocation.Expression.ToString());
+                    break;
+
+                case MemberAccessExpressionSyntax or ElementAccessExpressionSyntax
+                    when IsOutermostAccess((ExpressionSyntax)node) && !IsAssigned((ExpressionSyntax)node):
+                    var target = (ExpressionSyntax)node;
+
+                    // For data.Items.Any(...) the member read is data.Items, not the method
+                    if (node is MemberAccessExpressionSyntax method
+                        && node.Parent is InvocationExpressionSyntax call
+                        && call.Expression == node)
+                        target = method.Expression;
+
+                    if (GetDataPath(target, scope) is { } read)
+                        dependency.Reads.Add(read);
+                    break;
+            }
+        }
+    }
+
+    /// <summary>
+    /// Records the context key used by a call such as <c>ctx.GetChildContext&lt;T&gt;("Key")</c> or <c>ctx.GetData&lt;T&gt;()</c>.
+    /// </summary>
+    private static void AnalyzeContextCall(SimpleNameSyntax method, ArgumentListSyntax arguments, RuleDependency dependency)
+    {
+        switch (method.Identifier.Text)
+        {
+            case "GetData" when method is GenericNameSyntax { TypeArgumentList.Arguments: [var type] }:
+                dependency.ContextReads.Add($"typeof({type})");
+                break;
+
+            case "GetChildContext" or "HasChildContext" when GetContextKey(arguments) is { } key:
+                dependency.ContextReads.Add(key);
+                break;
+
+            case "SetChildContext" when GetContextKey(arguments) is { } key:
+                dependency.ContextWrites.Add(key);
+                break;
+        }
+    }
+
+    private static string? GetContextKey(ArgumentListSyntax arguments)
+        => arguments.Arguments.FirstOrDefault()?.Expression switch
+        {
+            LiteralExpressionSyntax literal when literal.IsKind(SyntaxKind.StringLiteralExpression) => literal.Token.ValueText,
+            { } expression => expression.ToString(),
+            null => null
+        };
+
+    private static bool IsOutermostAccess(ExpressionSyntax node)
+        => node.Parent switch
+        {
+            MemberAccessExpressionSyntax member => member.Expression != node,
+            ElementAccessExpressionSyntax element => element.Expression != node,
+            _ => true
+        };
+
+    private static bool IsAssigned(ExpressionSyntax node)
+        => node.Parent switch
+        {
+            AssignmentExpressionSyntax assignment => assignment.Left == node,
+            PostfixUnaryExpressionSyntax => true,
+            PrefixUnaryExpressionSyntax prefix =>
+                prefix.IsKind(SyntaxKind.PreIncrementExpression) || prefix.IsKind(SyntaxKind.PreDecrementExpression),
+            _ => false
+        };
+
+    /// <summary>
+    /// Converts an access rooted at a tracked parameter into a member path from the ruleset's data,
+    /// such as <c>Items[].Price</c>.
+    /// </summary>
+    private static string? GetDataPath(ExpressionSyntax expression, Dictionary<string, string> scope)
+    {
+        var segments = new Stack<string>();
+        var current = expression;
+
+        while (true)
+        {
+            switch (current)
+            {
+                case MemberAccessExpressionSyntax member:
+                    segments.Push("." + member.Name.Identifier.Text);
+                    current = member.Expression;
+                    continue;
+
+                case ElementAccessExpressionSyntax element:
+                    segments.Push("[]");
+                    current = element.Expression;
+                    continue;
+
+                case IdentifierNameSyntax identifier
+                    when segments.Count > 0 && scope.TryGetValue(identifier.Identifier.Text, out var path):
+                    return JoinPath(path, string.Concat(segments).TrimStart('.'));
+
+                default:
+                    return null;
+            }
+        }
+    }
+
+    /// <summary>
+    /// Appends a member path to the path of the data it is relative to.
+    /// </summary>
+    private static string JoinPath(string path, string member)
+        => path.Length == 0 || member.StartsWith('[') ? path + member : $"{path}.{member}";
+
+    private static string? GetRootIdentifier(ExpressionSyntax expression)
+        => expression switch
+        {
+            IdentifierNameSyntax identifier => identifier.Identifier.Text,
+            MemberAccessExpressionSyntax member => GetRootIdentifier(member.Expression),
+            ElementAccessExpressionSyntax element => GetRootIdentifier(element.Expression),
+            _ => null
+        };
+}
@@ -0,0 +1,100 @@
+namespace Winterflood.RuleEngine.Compiler.Configuration;
+
+/// <summary>
+/// Describes the members of the rule data a rule reads and writes, the context keys it uses, and the functions it calls.
+/// </summary>
+/// <remarks>
+/// Member paths are relative to the data of the ruleset the rule belongs to. A rule bound to a member of that data,
+/// or evaluating a nested or branch ruleset, reports the members used within it as paths from the same data.
+/// </remarks>
+public class RuleDependency
+{
+    /// <summary>
+    /// The name of the ruleset defining the rule.
+    /// </summary>
+    public string RuleSetName { get; set; } = string.Empty;
+
+    /// <summary>
+    /// The name of the rule.
+    /// </summary>
+    public string RuleName { get; set; } = string.Empty;
+
+    /// <summary>
+    /// The name of the ruleset declaring the rule, if the ruleset inherits it through <c>Includes</c>.
+    /// </summary>
+    public string? IncludedFrom { get; set; }
+
+    /// <summary>
+    /// The paths of the data members the rule reads, such as <c>Customer.Age</c> or <c>Items[].Price</c>.
+    /// </summary>
+    public SortedSet<string> Reads { get; set; } = new(StringComparer.Ordinal);
+
+    /// <summary>
+    /// The paths of the data members the rule assigns.
+    /// </summary>
+    public SortedSet<string> Writes { get; set; } = new(StringComparer.Ordinal);
+
+    /// <summary>
+    /// The keys of the child contexts the rule reads, and the caller data it retrieves, such as <c>typeof(RateService)</c>.
+    /// </summary>
+    public SortedSet<string> ContextReads { get; set; } = new(StringComparer.Ordinal);
+
+    /// <summary>
+    /// The keys of the child contexts the rule sets.
+    /// </summary>
+    public SortedSet<string> ContextWrites { get; set; } = new(StringComparer.Ordinal);
+
+    /// <summary>
+    /// The functions the rule calls that are not members of the data, such as <c>Math.Round</c>.
+    /// </summary>
+    public SortedSet<string> Calls { get; set; } = new(StringComparer.Ordinal);
+
+    /// <summary>
+    /// The name of the ruleset the rule delegates to, if it is a nested rule.
+    /// </summary>
+    public string? NestedRuleSet { get; set; }
+
+    /// <summary>
+    /// The name of the ruleset the rule's error policy branches to, if any.
+    /// </summary>
+    public string? BranchRuleSet { get; set; }
+}
+
+/// <summary>
+/// Represents the data and function dependencies of every rule in a configuration.
+/// </summary>
+/// <param name="rules">The dependencies of each rule.</param>
+public class RuleDependencyGraph(List<RuleDependency> rules)
+{
+    /// <summary>
+    /// The dependencies of each rule, in configuration order.
+    /// </summary>
+    public List<RuleDependency> Rules { get; } = rules;
+
+    /// <summary>
+    /// Finds the rules affected by a change to a data member, context key or function.
+    /// </summary>
+    /// <param name="keyOrFunction">
+    /// A data member path, such as <c>Customer.Age</c>, a context key, or a function name, such as <c>Math.Round</c>.
+    /// A member path also matches rules that touch its parent or child members.
+    /// </param>
+    /// <returns>The rules that read or write the member or context key, or call the function.</returns>
+    public List<RuleDependency> ImpactedBy(string keyOrFunction)
+        => Rules
+            .Where(rule =>
+                rule.Calls.Contains(keyOrFunction)
+                || rule.ContextReads.Contains(keyOrFunction)
+                || rule.ContextWrites.Contains(keyOrFunction)
+                || rule.Reads.Concat(rule.Writes).Any(path => Overlaps(path, keyOrFunction)))
+            .ToList();
+
+    /// <summary>
+    /// Determines whether two member paths are equal or one contains the other.
+    /// </summary>
+    internal static bool Overlaps(string path, string key)
+        => path == key
+           || path.StartsWith(key + ".", StringComparison.Ordinal)
+           || path.StartsWith(key + "[", StringComparison.Ordinal)
+           || key.StartsWith(path + ".", StringComparison.Ordinal)
+           || key.StartsWith(path + "[", StringComparison.Ordinal);
+}
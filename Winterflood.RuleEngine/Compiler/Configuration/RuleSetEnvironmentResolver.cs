@@ -0,0 +1,119 @@
+using Winterflood.RuleEngine.Compiler.Configuration.Models;
+
+namespace Winterflood.RuleEngine.Compiler.Configuration;
+
+/// <summary>
+/// Selects the ruleset variants of a configuration that apply to a particular environment.
+/// </summary>
+/// <remarks>
+/// A configuration may define several rulesets with the same name, each restricted to some environments
+/// through <see cref="RuleSetDefinition.Environments"/>. A variant for the environment takes precedence
+/// over a ruleset without environments, which applies everywhere else.
+/// </remarks>
+public static class RuleSetEnvironmentResolver
+{
+    /// <summary>
+    /// Creates a configuration containing only the rulesets that apply to an environment.
+    /// </summary>
+    /// <param name="configuration">The configuration containing every environment's rulesets.</param>
+    /// <param name="environment">The environment to select rulesets for.</param>
+    /// <returns>A configuration with at most one ruleset of each name.</returns>
+    /// <exception cref="InvalidOperationException">
+    /// Thrown when more than one ruleset with the same name applies to the environment.
+    /// </exception>
+    public static RuleEngineConfiguration ForEnvironment(RuleEngineConfiguration configuration, string environment)
+    {
+        var ruleSets = new List<RuleSetDefinition>();
+
+        foreach (var name in configuration.RuleSets.Select(r => r.Name).Distinct())
+        {
+            var ruleSet = ResolveRuleSet(configuration, name, environment, out var error);
+            if (error is not null)
+                throw new InvalidOperationException(error);
+
+            if (ruleSet is not null)
+                ruleSets.Add(ruleSet);
+        }
+
+        return new RuleEngineConfiguration
+        {
+            Types = configuration.Types,
+            RuleSets = ruleSets,
+            Environments = [environment],
+            Bundle = configuration.Bundle
+        };
+    }
+
+    /// <summary>
+    /// Determines whether a configuration still contains several variants of a ruleset, and so must be resolved
+    /// with <see cref="ForEnvironment"/> before it can be compiled.
+    /// </summary>
+    /// <param name="configuration">The configuration to inspect.</param>
+    /// <param name="error">When an environment must be selected, a message naming the rulesets that have variants.</param>
+    /// <returns><c>true</c> if an environment must be selected; otherwise, <c>false</c>.</returns>
+    public static bool RequiresEnvironment(RuleEngineConfiguration configuration, out string? error)
+    {
+        var names =
+            configuration.RuleSets
+                .GroupBy(r => r.Name)
+                .Where(group => group.Count() > 1)
+                .Select(group => $"'{group.Key}'")
+                .ToList();
+
+        error = names.Count > 0
+            ? $"RuleSet {string.Join(", ", names)} has variants for several environments; select an environment before compiling the configuration."
+            : null;
+
+        return names.Count > 0;
+    }
+
+    /// <summary>
+    /// Validates that every declared environment resolves exactly one variant of every ruleset.
+    /// </summary>
+    /// <param name="configuration">The configuration to validate.</param>
+    /// <returns>The validation errors, or an empty list if the configuration is valid.</returns>
+    public static List<string> Validate(RuleEngineConfiguration configuration)
+    {
+        var errors = new List<string>();
+
+        foreach (var ruleSet in configuration.RuleSets)
+        {
+            foreach (var environment in ruleSet.Environments.Where(e => !configuration.Environments.Contains(e)))
+                errors.Add($"RuleSet '{ruleSet.Name}' references undeclared environment '{environment}'.");
+        }
+
+        foreach (var environment in configuration.Environments)
+        {
+            foreach (var name in configuration.RuleSets.Select(r => r.Name).Distinct())
+            {
+                var ruleSet = ResolveRuleSet(configuration, name, environment, out var error);
+
+                if (error is not null)
+                    errors.Add(error);
+                else if (ruleSet is null)
+                    errors.Add($"RuleSet '{name}' is not defined for environment '{environment}'.");
+            }
+        }
+
+        return errors;
+    }
+
+    private static RuleSetDefinition? ResolveRuleSet(
+        RuleEngineConfiguration configuration,
+        string name,
+        string environment,
+        out string? error)
+    {
+        var variants = configuration.RuleSets.Where(r => r.Name == name).ToList();
+
+        var candidates = variants.Where(r => r.Environments.Contains(environment)).ToList();
+        if (candidates.Count == 0)
+            candidates = variants.Where(r => r.Environments.Count == 0).ToList();
+
+        error = candidates.Count > 1
+            ? $"RuleSet '{name}' has {candidates.Count} variants for environment '{environment}'."
+            : null;
+
+        return candidates.Count == 1 ? candidates[0] : null;
+    }
+}
@@ -0,0 +1,94 @@
+using Winterflood.RuleEngine.Compiler.Configuration.Models;
+
+namespace Winterflood.RuleEngine.Compiler.Configuration;
+
+/// <summary>
+/// Resolves the effective rules of a ruleset by expanding the rulesets it includes and applying its overrides.
+/// </summary>
+public static class RuleSetIncludeResolver
+{
+    /// <summary>
+    /// Resolves the ordered list of rules a ruleset evaluates.
+    /// </summary>
+    /// <param name="configuration">The configuration containing all ruleset definitions.</param>
+    /// <param name="ruleSet">The ruleset to resolve.</param>
+    /// <returns>
+    /// The rules of every included ruleset in declaration order, followed by the ruleset's own rules.
+    /// Rules declaring <see cref="RuleDefinition.Overrides"/> replace the named included rule in place.
+    /// </returns>
+    /// <exception cref="InvalidOperationException">
+    /// Thrown when an included ruleset is missing, operates on a different data type, is included cyclically,
+    /// when an override targets a rule that was not included or the overriding rule itself, or when two different rules
+    /// share a name without one overriding the other.
+    /// </exception>
+    public static List<RuleDefinition> ResolveRules(RuleEngineConfiguration configuration, RuleSetDefinition ruleSet)
+    {
+        return ResolveRules(configuration, ruleSet, []);
+    }
+
+    private static List<RuleDefinition> ResolveRules(
+        RuleEngineConfiguration configuration,
+        RuleSetDefinition ruleSet,
+        HashSet<string> resolving)
+    {
+        if (!resolving.Add(ruleSet.Name))
+            throw new InvalidOperationException($"Cyclic include detected for RuleSet '{ruleSet.Name}'.");
+
+        var rules = new List<RuleDefinition>();
+
+        foreach (var includeName in ruleSet.Includes)
+        {
+            var included =
+                configuration.RuleSets.FirstOrDefault(r => r.Name == includeName)
+                ?? throw new InvalidOperationException(
+                    $"RuleSet '{ruleSet.Name}' includes unknown RuleSet '{includeName}'.");
+
+            if (included.DataType != ruleSet.DataType)
+                throw new InvalidOperationException(
+                    $"RuleSet '{ruleSet.Name}' ({ruleSet.DataType}) cannot include RuleSet '{includeName}' ({included.DataType}).");
+
+            foreach (var rule in ResolveRules(configuration, included, resolving))
+            {
+                // Rulesets reachable through more than one include contribute their rules once
+                if (!rules.Contains(rule))
+                    AddRule(ruleSet, rules, rule);
+            }
+        }
+
+        foreach (var rule in ruleSet.Rules)
+        {
+            if (rule.Overrides is null)
+            {
+                AddRule(ruleSet, rules, rule);
+                continue;
+            }
+
+            if (rule.Overrides == rule.RuleName)
+                throw new InvalidOperationException(
+                    $"Rule '{rule.RuleName}' in RuleSet '{ruleSet.Name}' overrides itself; give the overriding rule its own name.");
+
+            var index = rules.FindIndex(existing => existing.RuleName == rule.Overrides);
+            if (index < 0)
+                throw new InvalidOperationException(
+                    $"Rule '{rule.RuleName}' in RuleSet '{ruleSet.Name}' overrides unknown included rule '{rule.Overrides}'.");
+
+            AddRule(ruleSet, rules, rule, replacing: index);
+        }
+
+        resolving.Remove(ruleSet.Name);
+        return rules;
+    }
+
+    private static void AddRule(RuleSetDefinition ruleSet, List<RuleDefinition> rules, RuleDefinition rule, int? replacing = null)
+    {
+        // The rule being overridden is replaced, so only the other rules can clash with the overriding rule
+        if (rules.Where((_, index) => index != replacing).Any(existing => existing.RuleName == rule.RuleName))
+            throw new InvalidOperationException(
+                $"RuleSet '{ruleSet.Name}' contains more than one rule named '{rule.RuleName}'; use Overrides to replace an included rule.");
+
+        if (replacing is { } index)
+            rules[index] = rule;
+        else
+            rules.Add(rule);
+    }
+}
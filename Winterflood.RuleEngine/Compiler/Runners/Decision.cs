@@ -0,0 +1,84 @@
+using System.Text.Json;
+using Winterflood.RuleEngine.Compiler.Configuration.Bundle;
+using Winterflood.RuleEngine.Engine.Context;
+
+namespace Winterflood.RuleEngine.Compiler.Runners;
+
+/// <summary>
+/// Records the provenance of an automated decision: the outcome of a RuleSet execution together with
+/// the evaluation trail, the exact configuration and engine version used, the input, who requested it and when it was made.
+/// </summary>
+/// <remarks>
+/// A decision serializes to JSON with <see cref="ToJson"/> so that it can be stored for later audit.
+/// </remarks>
+public class Decision
+{
+    /// <summary>
+    /// The name of the RuleSet that made the decision.
+    /// </summary>
+    public string RuleSetName { get; init; } = string.Empty;
+
+    /// <summary>
+    /// The name of the data type the RuleSet operated on.
+    /// </summary>
+    public string DataType { get; init; } = string.Empty;
+
+    /// <summary>
+    /// The outcome of the decision, or <c>null</c> if the RuleSet could not be executed.
+    /// </summary>
+    public bool? Result { get; init; }
+
+    /// <summary>
+    /// The message of the error that prevented the decision, if any.
+    /// </summary>
+    public string? Error { get; init; }
+
+    /// <summary>
+    /// The context recorded while evaluating the RuleSet.
+    /// </summary>
+    public RootContext Context { get; init; } = new();
+
+    /// <summary>
+    /// The hash of the configuration the RuleSet was compiled from, identifying the exact rules that were used.
+    /// It is stamped into the assembly at compile time and read back from it when the decision is made.
+    /// </summary>
+    public string ConfigurationHash { get; init; } = string.Empty;
+
+    /// <summary>
+    /// The informational version of the rule engine package that made the decision.
+    /// </summary>
+    public string EngineVersion { get; init; } = string.Empty;
+
+    /// <summary>
+    /// The bundle the configuration was loaded from and how it was verified,
+    /// or <c>null</c> if the configuration was not loaded from a bundle.
+    /// </summary>
+    public RuleBundleMetadata? Bundle { get; init; }
+
+    /// <summary>
+    /// The hex encoded SHA-256 hash of the JSON input data.
+    /// </summary>
+    public string InputHash { get; init; } = string.Empty;
+
+    /// <summary>
+    /// The user or system that requested the decision, or <c>null</c> if it was not given.
+    /// </summary>
+    public string? Actor { get; init; }
+
+    /// <summary>
+    /// When evaluation of the decision started.
+    /// </summary>
+    public DateTimeOffset StartedAt { get; init; }
+
+    /// <summary>
+    /// When evaluation of the decision completed.
+    /// </summary>
+    public DateTimeOffset CompletedAt { get; init; }
+
+    /// <summary>
+    /// Serializes the decision to indented JSON for long-term storage.
+    /// </summary>
+    /// <returns>The JSON representation of the decision.</returns>
+    public string ToJson()
+        => JsonSerializer.Serialize(this, new JsonSerializerOptions { WriteIndented = true });
+}
@@ -62,15 +62,30 @@ public static class EvaluationExecutor
     /// </remarks>
     public static bool TryInvokeEvaluate(MethodInfo method, object instance, object input, RootContext context,
         ILogger logger)
+        => TryInvokeEvaluate(method, instance, input, context, logger, out _);
+
+    /// <summary>
+    /// Invokes the <c>Evaluate</c> method on the given RuleSet instance and captures the value it returned.
+    /// </summary>
+    /// <param name="method">The <see cref="MethodInfo"/> representing the Evaluate method.</param>
+    /// <param name="instance">The instance of the RuleSet to invoke the method on.</param>
+    /// <param name="input">The rule input data object.</param>
+    /// <param name="context">The <see cref="RootContext"/> for evaluation tracking.</param>
+    /// <param name="logger">Logger used to report invocation errors.</param>
+    /// <param name="result">The value returned by the RuleSet's <c>Evaluate</c> method, or <c>false</c> if invocation failed.</param>
+    /// <returns><c>true</c> if the method was invoked without error; otherwise, <c>false</c>.</returns>
+    public static bool TryInvokeEvaluate(MethodInfo method, object instance, object input, RootContext context,
+        ILogger logger, out bool result)
     {
         try
         {
-            method.Invoke(instance, [input, context]);
+            result = method.Invoke(instance, [input, context]) is true;
             return true;
         }
         catch (Exception ex)
         {
             logger.LogError(ex, "Evaluation failed");
+            result = false;
             return false;
         }
     }
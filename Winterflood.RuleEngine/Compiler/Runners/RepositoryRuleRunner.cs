@@ -0,0 +1,241 @@
+using System.Reflection;
+using Microsoft.Extensions.Logging;
+using Winterflood.RuleEngine.Compiler.Compiler;
+using Winterflood.RuleEngine.Compiler.Configuration.Models;
+using Winterflood.RuleEngine.Compiler.Configuration.Repository;
+using Winterflood.RuleEngine.Engine.Context;
+
+namespace Winterflood.RuleEngine.Compiler.Runners;
+
+/// <summary>
+/// Executes rulesets stored in an <see cref="IRuleSetRepository"/>, loading and compiling each ruleset
+/// the first time it is executed and recompiling it after it changes in the repository.
+/// </summary>
+/// <remarks>
+/// A ruleset is compiled together with the rulesets it references through nested rules, includes and
+/// error branches. A change to any of those rulesets invalidates the compilation, and the ruleset is
+/// recompiled on its next execution. The runner is safe to share between threads.
+/// </remarks>
+public sealed class RepositoryRuleRunner : IDisposable
+{
+    private readonly IRuleSetRepository _repository;
+    private readonly List<DataModelDefinition> _types;
+    private readonly ILoggerFactory _loggerFactory;
+    private readonly CompilationCache _cache;
+    private readonly ILogger<RepositoryRuleRunner> _logger;
+    private readonly IDisposable _watcher;
+
+    private readonly object _lock = new();
+    private readonly Dictionary<string, CompiledRuleSet> _compiled = new();
+    private int _version;
+
+    /// <summary>
+    /// Initializes a new instance of the <see cref="RepositoryRuleRunner"/> class and starts watching the repository.
+    /// </summary>
+    /// <param name="repository">The repository to load rulesets from.</param>
+    /// <param name="types">The data types the rulesets operate on.</param>
+    /// <param name="loggerFactory">Logger factory for structured logging.</param>
+    /// <param name="cache">
+    /// The cache compilations are reused from, such as one shared with other runners; the runner keeps a cache of its own when omitted.
+    /// </param>
+    /// <exception cref="ArgumentNullException">Thrown when <paramref name="repository"/> or <paramref name="types"/> is null.</exception>
+    public RepositoryRuleRunner(
+        IRuleSetRepository repository,
+        IEnumerable<DataModelDefinition> types,
+        ILoggerFactory loggerFactory,
+        CompilationCache? cache = null)
+    {
+        _repository = repository ?? throw new ArgumentNullException(nameof(repository));
+        _types = types?.ToList() ?? throw new ArgumentNullException(nameof(types));
+        _loggerFactory = loggerFactory;
+        _cache = cache ?? new CompilationCache();
+        _logger = loggerFactory.CreateLogger<RepositoryRuleRunner>();
+        _watcher = repository.Watch(OnRuleSetChanged);
+    }
+
+    /// <summary>
+    /// Determines whether a compiled ruleset is currently loaded.
+    /// </summary>
+    /// <param name="ruleSetName">The name of the ruleset.</param>
+    /// <returns><c>true</c> if the ruleset is compiled and up to date; otherwise, <c>false</c>.</returns>
+    public bool IsLoaded(string ruleSetName)
+    {
+        lock (_lock)
+            return _compiled.ContainsKey(ruleSetName);
+    }
+
+    /// <summary>
+    /// Executes a ruleset from the repository, compiling it first if it is not loaded.
+    /// </summary>
+    /// <param name="ruleSetName">The name of the ruleset to execute.</param>
+    /// <param name="jsonData">The JSON input data.</param>
+    /// <param name="context">Optional context to record the evaluation into.</param>
+    /// <returns>
+    /// The execution result. Its <see cref="RuleSetExecutionResult.Result"/> is <c>null</c> when the ruleset
+    /// could not be loaded, compiled or executed, and its <see cref="RuleSetExecutionResult.Error"/> describes why.
+    /// </returns>
+    public RuleSetExecutionResult Execute(string ruleSetName, string? jsonData, RootContext? context = null)
+    {
+        var compiled = GetOrCompile(ruleSetName, out var error);
+        if (compiled is null)
+            return new RuleSetExecutionResult(null, context ?? new RootContext(), error);
+
+        var ruleSet = compiled.Configuration.RuleSets.First(r => r.Name == ruleSetName);
+
+        return RuleRunner.ExecuteRulesetDetailed(
+            _loggerFactory,
+            compiled.Assembly,
+            ruleSet.Name,
+            ruleSet.DataType,
+            jsonData,
+            context);
+    }
+
+    /// <summary>
+    /// Stops watching the repository.
+    /// </summary>
+    public void Dispose()
+    {
+        _watcher.Dispose();
+    }
+
+    private CompiledRuleSet? GetOrCompile(string ruleSetName, out string? error)
+    {
+        int version;
+        error = null;
+
+        lock (_lock)
+        {
+            if (_compiled.TryGetValue(ruleSetName, out var existing))
+                return existing;
+
+            version = _version;
+        }
+
+        var configuration = LoadConfiguration(ruleSetName, out error);
+        if (configuration is null)
+            return null;
+
+        CompilationResult compilation;
+        try
+        {
+            compilation = _cache.GetOrCompile(configuration, _loggerFactory);
+        }
+        catch (InvalidOperationException ex)
+        {
+            // Configurations the compiler rejects, such as includes of unknown rulesets, are reported like compilation errors
+            _logger.LogError(ex, "[Compilation Failed] RuleSet={RuleSetName}", ruleSetName);
+            error = ex.Message;
+            return null;
+        }
+
+        if (!compilation.Success || compilation.CompiledAssembly is null)
+        {
+            var failedUnits = compilation.UnitResults.Where(unit => !unit.Success).ToList();
+            foreach (var unit in failedUnits)
+                _logger.LogError("[Compilation Failed] RuleSet={RuleSetName} Type={Type}: {Message}", ruleSetName, unit.Type, unit.Message);
+
+            error = $"RuleSet '{ruleSetName}' could not be compiled: {string.Join("; ", failedUnits.Select(unit => $"{unit.Type}: {unit.Message}"))}";
+            return null;
+        }
+
+        var compiled = new CompiledRuleSet(configuration, compilation.CompiledAssembly);
+
+        lock (_lock)
+        {
+            // A ruleset changed while compiling, so the compilation may already be stale; use it once without keeping it
+            if (version == _version)
+                _compiled[ruleSetName] = compiled;
+        }
+
+        _logger.LogInformation("[Loaded] RuleSet={RuleSetName} RuleSets={RuleSetCount}", ruleSetName, configuration.RuleSets.Count);
+        return compiled;
+    }
+
+    /// <summary>
+    /// Loads a ruleset together with every ruleset it references, directly or indirectly.
+    /// </summary>
+    private RuleEngineConfiguration? LoadConfiguration(string ruleSetName, out string? error)
+    {
+        error = null;
+
+        var ruleSets = new List<RuleSetDefinition>();
+        var loaded = new HashSet<string>();
+        var pending = new Queue<string>([ruleSetName]);
+
+        while (pending.Count > 0)
+        {
+            var name = pending.Dequeue();
+            if (!loaded.Add(name))
+                continue;
+
+            var ruleSet = _repository.LoadRuleSet(name);
+            if (ruleSet is null)
+            {
+                _logger.LogError("RuleSet={RuleSetName} references RuleSet={MissingRuleSet} which is not in the repository", ruleSetName, name);
+                error = name == ruleSetName
+                    ? $"RuleSet '{name}' is not in the repository."
+                    : $"RuleSet '{ruleSetName}' references RuleSet '{name}' which is not in the repository.";
+                return null;
+            }
+
+            // Rulesets are compiled and looked up by the name they define, which must be the name they were loaded by
+            if (ruleSet.Name != name)
+            {
+                _logger.LogError("RuleSet={RuleSetName} was loaded as RuleSet={LoadedName}", ruleSet.Name, name);
+                error = $"RuleSet '{name}' loaded from the repository is named '{ruleSet.Name}'.";
+                return null;
+            }
+
+            ruleSets.Add(ruleSet);
+
+            foreach (var reference in GetReferences(ruleSet))
+                pending.Enqueue(reference);
+        }
+
+        return new RuleEngineConfiguration { Types = _types, RuleSets = ruleSets };
+    }
+
+    private static IEnumerable<string> GetReferences(RuleSetDefinition ruleSet)
+    {
+        foreach (var include in ruleSet.Includes)
+            yield return include;
+
+        foreach (var rule in ruleSet.Rules)
+        {
+            if (rule is NestedRuleDefinition nested)
+                yield return nested.RulesetName;
+
+            if (rule.ErrorPolicy?.RuleSetName is { } branch)
+                yield return branch;
+        }
+    }
+
+    private void OnRuleSetChanged(string ruleSetName)
+    {
+        lock (_lock)
+        {
+            _version++;
+
+            var stale =
+                _compiled
+                    .Where(entry => entry.Value.Configuration.RuleSets.Any(r => r.Name == ruleSetName))
+                    .Select(entry => entry.Key)
+                    .ToList();
+
+            foreach (var name in stale)
+                _compiled.Remove(name);
+
+            _logger.LogInformation(
+                "[RuleSet Changed] RuleSet={RuleSetName} Invalidated={InvalidatedCount}",
+                ruleSetName,
+                stale.Count);
+        }
+    }
+
+    private sealed class CompiledRuleSet(RuleEngineConfiguration configuration, Assembly assembly)
+    {
+        public RuleEngineConfiguration Configuration { get; } = configuration;
+        public Assembly Assembly { get; } = assembly;
+    }
+}
@@ -1,6 +1,11 @@
 using System.Reflection;
+using System.Security.Cryptography;
+using System.Text;
 using System.Text.Json;
 using Microsoft.Extensions.Logging;
+using Winterflood.RuleEngine.Compiler.Compiler;
+using Winterflood.RuleEngine.Compiler.Configuration;
+using Winterflood.RuleEngine.Compiler.Configuration.Models;
 using Winterflood.RuleEngine.Engine.Context;
 
 namespace Winterflood.RuleEngine.Compiler.Runners;
@@ -14,12 +19,22 @@ public static class RuleRunner
     /// <summary>
     /// Executes a compiled RuleSet by deserializing the input data and invoking the Evaluate method.
     /// </summary>
-    public static void ExecuteRuleset(
+    /// <param name="loggerFactory">The logger factory for logging execution details.</param>
+    /// <param name="assembly">The compiled assembly containing the RuleSet and data types.</param>
+    /// <param name="ruleSetName">The name of the RuleSet to execute.</param>
+    /// <param name="dataType">The name of the data type the RuleSet operates on.</param>
+    /// <param name="jsonData">The JSON input data.</param>
+    /// <param name="context">Optional context to record the evaluation into; a new context is used when omitted.</param>
+    /// <returns>
+    /// The result of the RuleSet evaluation, or <c>null</c> if the RuleSet could not be executed.
+    /// </returns>
+    public static bool? ExecuteRuleset(
         ILoggerFactory loggerFactory,
         Assembly assembly,
         string? ruleSetName,
         string? dataType,
-        string? jsonData)
+        string? jsonData,
+        RootContext? context = null)
     {
 
         var logger = loggerFactory.CreateLogger(nameof(RuleRunner));
@@ -27,7 +42,7 @@ public static class RuleRunner
         if (string.IsNullOrWhiteSpace(ruleSetName) || string.IsNullOrWhiteSpace(dataType))
         {
             logger.LogError("Invalid input: RuleSetName={RuleSetName}, DataType={DataType}", ruleSetName, dataType);
-            return;
+            return null;
         }
 
         logger.LogInformation("Executing RuleSet={RuleSetName}", ruleSetName);
@@ -40,24 +55,223 @@ public static class RuleRunner
                 loggerFactory);
 
         if (ruleSetInstance is null)
-            return;
+            return null;
 
         if (!CompiledDataResolver.TryResolveDataObject(assembly, dataType, jsonData, logger, out var data))
-            return;
-
-        var context = new RootContext();
-        var success =
-            EvaluationExecutor.TryEvaluate(
-                ruleSetInstance,
-                data!,
-                context,
-                logger,
-                ruleSetName);
+            return null;
+
+        var evaluateMethod = EvaluationExecutor.ResolveEvaluateMethod(ruleSetInstance, logger, ruleSetName);
+        if (evaluateMethod is null)
+            return null;
+
+        context ??= new RootContext();
+        var executed =
+            EvaluationExecutor.TryInvokeEvaluate(
+                evaluateMethod,
+                ruleSetInstance,
+                data!,
+                context,
+                logger,
+                out var success);
 
         logger.LogInformation(
             "RuleSet Execution Complete: RuleSetName={RuleSetName}, Result={Result}, Data={Data}",
             ruleSetName,
             success,
             JsonSerializer.Serialize(data, new JsonSerializerOptions { WriteIndented = true }));
+
+        return executed ? success : null;
+    }
+
+    /// <summary>
+    /// Executes a compiled RuleSet and returns its result together with the context recorded during evaluation.
+    /// </summary>
+    /// <param name="loggerFactory">The logger factory for logging execution details.</param>
+    /// <param name="assembly">The compiled assembly containing the RuleSet and data types.</param>
+    /// <param name="ruleSetName">The name of the RuleSet to execute.</param>
+    /// <param name="dataType">The name of the data type the RuleSet operates on.</param>
+    /// <param name="jsonData">The JSON input data.</param>
+    /// <param name="context">Optional context to record the evaluation into, for example with caller data attached.</param>
+    /// <returns>
+    /// The execution result, including the rules evaluated so far and the failing rule when the evaluation raised an error.
+    /// </returns>
+    public static RuleSetExecutionResult ExecuteRulesetDetailed(
+        ILoggerFactory loggerFactory,
+        Assembly assembly,
+        string? ruleSetName,
+        string? dataType,
+        string? jsonData,
+        RootContext? context = null)
+    {
+        context ??= new RootContext();
+        var result = ExecuteRuleset(loggerFactory, assembly, ruleSetName, dataType, jsonData, context);
+
+        return new RuleSetExecutionResult(result, context);
+    }
+
+    /// <summary>
+    /// Compiles a configuration and executes one of its RuleSets.
+    /// </summary>
+    /// <param name="loggerFactory">The logger factory for logging execution details.</param>
+    /// <param name="configuration">The configuration defining the RuleSet.</param>
+    /// <param name="ruleSetName">The name of the RuleSet to execute.</param>
+    /// <param name="jsonData">The JSON input data.</param>
+    /// <param name="context">Optional context to record the evaluation into, for example with caller data attached.</param>
+    /// <param name="cache">
+    /// Optional cache to reuse the compilation of the configuration from; the configuration is compiled on every call when omitted.
+    /// </param>
+    /// <returns>
+    /// The execution result. Its <see cref="RuleSetExecutionResult.Result"/> is <c>null</c> when the configuration
+    /// could not be compiled or the RuleSet could not be executed.
+    /// </returns>
+    /// <exception cref="InvalidOperationException">
+    /// Thrown when <paramref name="ruleSetName"/> is not defined in <paramref name="configuration"/>,
+    /// or the configuration contains variants of a ruleset that have not been resolved for an environment.
+    /// </exception>
+    public static RuleSetExecutionResult ExecuteRulesetDetailed(
+        ILoggerFactory loggerFactory,
+        RuleEngineConfiguration configuration,
+        string ruleSetName,
+        string? jsonData,
+        RootContext? context = null,
+        CompilationCache? cache = null)
+    {
+        var ruleSet = GetRuleSet(configuration, ruleSetName);
+
+        var assembly = Compile(configuration, loggerFactory, cache);
+        if (assembly is null)
+            return new RuleSetExecutionResult(null, context ?? new RootContext());
+
+        return ExecuteRulesetDetailed(loggerFactory, assembly, ruleSet.Name, ruleSet.DataType, jsonData, context);
+    }
+
+    /// <summary>
+    /// Executes a RuleSet from a compiled configuration and records the provenance of the resulting decision.
+    /// </summary>
+    /// <param name="loggerFactory">The logger factory for logging execution details.</param>
+    /// <param name="assembly">The assembly compiled from <paramref name="configuration"/>.</param>
+    /// <param name="configuration">The configuration the assembly was compiled from.</param>
+    /// <param name="ruleSetName">The name of the RuleSet to execute.</param>
+    /// <param name="jsonData">The JSON input data.</param>
+    /// <param name="context">Optional context to record the evaluation into, for example with caller data attached.</param>
+    /// <param name="actor">Optional user or system that requested the decision, recorded as <see cref="Decision.Actor"/>.</param>
+    /// <param name="timeProvider">
+    /// Optional clock the decision is timestamped with; <see cref="TimeProvider.System"/> is used when omitted.
+    /// </param>
+    /// <returns>The decision, including its outcome, evaluation trail and provenance.</returns>
+    /// <exception cref="InvalidOperationException">
+    /// Thrown when <paramref name="ruleSetName"/> is not defined in <paramref name="configuration"/>,
+    /// or <paramref name="assembly"/> was not compiled from <paramref name="configuration"/>.
+    /// </exception>
+    public static Decision ExecuteDecision(
+        ILoggerFactory loggerFactory,
+        Assembly assembly,
+        RuleEngineConfiguration configuration,
+        string ruleSetName,
+        string? jsonData,
+        RootContext? context = null,
+        string? actor = null,
+        TimeProvider? timeProvider = null)
+    {
+        var ruleSet = GetRuleSet(configuration, ruleSetName);
+
+        // The hash is read from the assembly, so the decision records the rules that actually ran
+        var configurationHash = SyntaxTreeCompiler.GetConfigurationHash(assembly);
+        if (configurationHash != RuleDefinitionParser.ComputeHash(configuration, loggerFactory))
+            throw new InvalidOperationException($"The assembly executing RuleSet '{ruleSetName}' was not compiled from the given configuration.");
+
+        timeProvider ??= TimeProvider.System;
+
+        var startedAt = timeProvider.GetUtcNow();
+        var execution = ExecuteRulesetDetailed(loggerFactory, assembly, ruleSet.Name, ruleSet.DataType, jsonData, context);
+
+        return new Decision
+        {
+            RuleSetName = ruleSet.Name,
+            DataType = ruleSet.DataType,
+            Result = execution.Result,
+            Error = execution.Error,
+            Context = execution.Context,
+            ConfigurationHash = configurationHash,
+            EngineVersion = EngineVersion,
+            Bundle = configuration.Bundle,
+            InputHash = Convert.ToHexString(SHA256.HashData(Encoding.UTF8.GetBytes(jsonData ?? string.Empty))),
+            Actor = actor,
+            StartedAt = startedAt,
+            CompletedAt = timeProvider.GetUtcNow()
+        };
+    }
+
+    /// <summary>
+    /// Compiles a configuration, executes one of its RuleSets and records the provenance of the resulting decision.
+    /// </summary>
+    /// <param name="loggerFactory">The logger factory for logging execution details.</param>
+    /// <param name="configuration">The configuration defining the RuleSet.</param>
+    /// <param name="ruleSetName">The name of the RuleSet to execute.</param>
+    /// <param name="jsonData">The JSON input data.</param>
+    /// <param name="context">Optional context to record the evaluation into, for example with caller data attached.</param>
+    /// <param name="cache">
+    /// Optional cache to reuse the compilation of the configuration from; the configuration is compiled on every call when omitted.
+    /// </param>
+    /// <param name="actor">Optional user or system that requested the decision, recorded as <see cref="Decision.Actor"/>.</param>
+    /// <param name="timeProvider">
+    /// Optional clock the decision is timestamped with; <see cref="TimeProvider.System"/> is used when omitted.
+    /// </param>
+    /// <returns>The decision, including its outcome, evaluation trail and provenance.</returns>
+    /// <exception cref="InvalidOperationException">
+    /// Thrown when <paramref name="ruleSetName"/> is not defined in <paramref name="configuration"/>,
+    /// the configuration contains variants of a ruleset that have not been resolved for an environment,
+    /// or the configuration could not be compiled.
+    /// </exception>
+    public static Decision ExecuteDecision(
+        ILoggerFactory loggerFactory,
+        RuleEngineConfiguration configuration,
+        string ruleSetName,
+        string? jsonData,
+        RootContext? context = null,
+        CompilationCache? cache = null,
+        string? actor = null,
+        TimeProvider? timeProvider = null)
+    {
+        GetRuleSet(configuration, ruleSetName);
+
+        var assembly =
+            Compile(configuration, loggerFactory, cache)
+            ?? throw new InvalidOperationException($"The configuration of RuleSet '{ruleSetName}' could not be compiled.");
+
+        return ExecuteDecision(loggerFactory, assembly, configuration, ruleSetName, jsonData, context, actor, timeProvider);
+    }
+
+    /// <summary>
+    /// The version of the rule engine package, including any pre-release or build metadata.
+    /// </summary>
+    private static string EngineVersion =>
+        typeof(RuleRunner).Assembly.GetCustomAttribute<AssemblyInformationalVersionAttribute>()?.InformationalVersion
+        ?? typeof(RuleRunner).Assembly.GetName().Version?.ToString()
+        ?? string.Empty;
+
+    private static RuleSetDefinition GetRuleSet(RuleEngineConfiguration configuration, string ruleSetName)
+    {
+        // Without an environment there is no telling which variant of a ruleset was meant
+        if (RuleSetEnvironmentResolver.RequiresEnvironment(configuration, out var error))
+            throw new InvalidOperationException(error);
+
+        return configuration.RuleSets.FirstOrDefault(r => r.Name == ruleSetName)
+               ?? throw new InvalidOperationException($"RuleSet '{ruleSetName}' is not defined in the configuration.");
+    }
+
+    private static Assembly? Compile(RuleEngineConfiguration configuration, ILoggerFactory loggerFactory, CompilationCache? cache)
+    {
+        var compilation =
+            cache?.GetOrCompile(configuration, loggerFactory)
+            ?? SyntaxTreeCompiler.Compile(configuration, loggerFactory);
+        if (compilation.Success && compilation.CompiledAssembly is not null)
+            return compilation.CompiledAssembly;
+
+        var logger = loggerFactory.CreateLogger(nameof(RuleRunner));
+        foreach (var unit in compilation.UnitResults.Where(unit => !unit.Success))
+            logger.LogError("[Compilation Failed] Type={Type}: {Message}", unit.Type, unit.Message);
+
+        return null;
     }
 }
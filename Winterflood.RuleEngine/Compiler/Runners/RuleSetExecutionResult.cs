@@ -0,0 +1,40 @@
+using Winterflood.RuleEngine.Engine.Context;
+
+namespace Winterflood.RuleEngine.Compiler.Runners;
+
+/// <summary>
+/// Represents the outcome of executing a compiled RuleSet together with the context recorded during evaluation.
+/// The context is available even when the evaluation was aborted by an error.
+/// </summary>
+/// <param name="result">The result of the RuleSet evaluation, or <c>null</c> if the RuleSet could not be executed.</param>
+/// <param name="context">The context the evaluation was recorded into.</param>
+/// <param name="error">
+/// Why the RuleSet could not be executed when no rule raised the error, such as a configuration that could not be compiled.
+/// </param>
+public class RuleSetExecutionResult(bool? result, RootContext context, string? error = null)
+{
+    /// <summary>
+    /// The result of the RuleSet evaluation, or <c>null</c> if the RuleSet could not be executed.
+    /// </summary>
+    public bool? Result { get; } = result;
+
+    /// <summary>
+    /// The context recorded during evaluation, containing every rule evaluated before any error.
+    /// </summary>
+    public RootContext Context { get; } = context;
+
+    /// <summary>
+    /// The context of the rule whose error aborted the evaluation, or <c>null</c> if no rule raised an unhandled error.
+    /// </summary>
+    public RuleContext? FailedRule { get; } = result is null ? context.FailedRule : null;
+
+    /// <summary>
+    /// The message of the error that aborted the evaluation or prevented the RuleSet from being executed, if any.
+    /// </summary>
+    public string? Error { get; } = result is null ? context.FailedRule?.Error ?? error : null;
+
+    /// <summary>
+    /// Whether the evaluation was aborted because it exceeded the limits set with <see cref="RootContext.WithOptions"/>.
+    /// </summary>
+    public bool BudgetExceeded => Context.BudgetExceeded;
+}
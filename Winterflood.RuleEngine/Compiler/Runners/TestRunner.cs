@@ -17,12 +17,14 @@ public static class TestRunner
     /// <summary>
     /// Executes all defined rule set tests within the specified configuration against the compiled assembly.
     /// </summary>
-    public static void RunTests(
+    /// <returns><c>true</c> if every defined test passed; otherwise, <c>false</c>.</returns>
+    public static bool RunTests(
         Assembly compiledAssembly,
         RuleEngineConfiguration configuration,
         ILoggerFactory loggerFactory)
     {
         var logger = loggerFactory.CreateLogger(nameof(TestRunner));
+        var allPassed = true;
 
         foreach (var ruleSet in configuration.RuleSets)
         {
@@ -42,7 +44,10 @@ public static class TestRunner
             );
 
             if (ruleSetInstance is null)
+            {
+                allPassed = false;
                 continue;
+            }
 
             var evaluateMethod = EvaluationExecutor.ResolveEvaluateMethod(
                 ruleSetInstance,
@@ -51,7 +56,10 @@ public static class TestRunner
             );
 
             if (evaluateMethod is null)
+            {
+                allPassed = false;
                 continue;
+            }
 
             var dataParamType = evaluateMethod.GetParameters()[0].ParameterType;
 
@@ -75,12 +83,14 @@ public static class TestRunner
                     if (testData == null)
                     {
                         logger.LogError("Failed to deserialize test data: RuleSet={RuleSetName}", ruleSet.Name);
+                        allPassed = false;
                         continue;
                     }
                 }
                 catch (Exception ex)
                 {
                     logger.LogError(ex, "JSON deserialization failed for RuleSet={RuleSetName}", ruleSet.Name);
+                    allPassed = false;
                     continue;
                 }
 
@@ -97,7 +107,7 @@ public static class TestRunner
                             logger
                         );
 
-                    var passed = success && EvaluatePredicate(dataParamType, testData, test.Expect!);
+                    var passed = success && EvaluatePredicate(dataParamType, testData, test.Expect!, logger);
 
                     if (passed)
                     {
@@ -105,6 +115,8 @@ public static class TestRunner
                     }
                     else
                     {
+                        allPassed = false;
+
                         var serializedData =
                             JsonSerializer.Serialize(
                                 testData,
@@ -121,15 +133,18 @@ public static class TestRunner
                 catch (Exception ex)
                 {
                     logger.LogError(ex, "Exception during evaluation of RuleSet={RuleSetName}", ruleSet.Name);
+                    allPassed = false;
                 }
             }
         }
+
+        return allPassed;
     }
 
     /// <summary>
     /// Dynamically compiles and evaluates a predicate against a given object instance.
     /// </summary>
-    private static bool EvaluatePredicate(Type modelType, object model, string expression)
+    private static bool EvaluatePredicate(Type modelType, object model, string expression, ILogger logger)
     {
         try
         {
@@ -146,7 +161,11 @@ public static class TestRunner
         }
         catch (Exception ex)
         {
-            Console.WriteLine($"Failed to evaluate predicate '{expression}' for type {modelType.Name}: {ex.Message}");
+            logger.LogError(
+                ex,
+                "Failed to evaluate Predicate={Predicate} for Type={TypeName}",
+                expression,
+                modelType.Name);
             return false;
         }
     }
@@ -9,5 +9,10 @@
         /// 
         /// </summary>
         public static readonly string CompilerGenerated = "CompilerGenerated";
+
+        /// <summary>
+        /// The key of the assembly metadata recording the hash of the configuration an assembly was compiled from.
+        /// </summary>
+        public static readonly string ConfigurationHashMetadataKey = "ConfigurationHash";
     }
 }
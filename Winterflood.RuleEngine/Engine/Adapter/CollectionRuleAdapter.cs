@@ -59,7 +59,7 @@ public class CollectionRuleAdapter<T> : IRule<IEnumerable<T>> where T : class, I
 
         foreach (var item in itemsList)
         {
-            var ctx = new RootContext();
+            var ctx = rootContext.CreateNestedContext();
             rootContext.SetChildContext($"{_rule.Name}[{index}]", ctx);
             
             var passed = _rule.Evaluate(item, ctx);
@@ -0,0 +1,275 @@
+using System.Runtime.CompilerServices;
+using Microsoft.Extensions.Logging;
+using Microsoft.Extensions.Logging.Abstractions;
+using Winterflood.RuleEngine.Engine.Context;
+using Winterflood.RuleEngine.Engine.Data;
+using Winterflood.RuleEngine.Engine.Rule;
+using Winterflood.RuleEngine.Engine.RuleSet;
+
+namespace Winterflood.RuleEngine.Engine.Adapter;
+
+/// <summary>
+/// Adapts an <see cref="IRule{T}"/> so that exceptions raised while evaluating it are handled
+/// according to a <see cref="RuleErrorAction"/> instead of aborting the owning ruleset.
+/// </summary>
+/// <typeparam name="T">
+/// The type of rule data. Must be a class implementing <see cref="IRuleData"/> with a parameterless constructor.
+/// </typeparam>
+public class ErrorPolicyRuleAdapter<T> : IRule<T> where T : class, IRuleData, new()
+{
+    private readonly IRule<T> _rule;
+    private readonly RuleErrorAction _action;
+    private readonly int _retryCount;
+    private readonly IRuleSet<T>? _branch;
+    private readonly ILogger _logger;
+
+    /// <summary>
+    /// How an error raised while evaluating the rule was handled, for each context the rule was last evaluated in,
+    /// so that the success or failure logic that follows runs for the outcome of the handling rather than for the rule.
+    /// </summary>
+    private readonly ConditionalWeakTable<RootContext, HandledError> _handledErrors = new();
+
+    /// <summary>
+    /// Initializes a new instance of the <see cref="ErrorPolicyRuleAdapter{T}"/> class.
+    /// </summary>
+    /// <param name="rule">The rule to apply the error policy to.</param>
+    /// <param name="action">How an exception raised by the rule is handled.</param>
+    /// <param name="retryCount">The number of additional attempts made when <paramref name="action"/> is <see cref="RuleErrorAction.Retry"/>.</param>
+    /// <param name="branch">The ruleset evaluated in place of the rule when <paramref name="action"/> is <see cref="RuleErrorAction.Branch"/>.</param>
+    /// <param name="loggerFactory">Optional logger factory for creating loggers.</param>
+    /// <exception cref="ArgumentNullException">Thrown when <paramref name="rule"/> is null.</exception>
+    /// <exception cref="ArgumentException">
+    /// Thrown when a retry policy has no retries or a branch policy has no branch ruleset.
+    /// </exception>
+    public ErrorPolicyRuleAdapter(
+        IRule<T> rule,
+        RuleErrorAction action,
+        int retryCount = 0,
+        IRuleSet<T>? branch = null,
+        ILoggerFactory? loggerFactory = null)
+    {
+        _rule = rule ?? throw new ArgumentNullException(nameof(rule));
+
+        if (action == RuleErrorAction.Retry && retryCount < 1)
+            throw new ArgumentException("A retry policy requires at least one retry.", nameof(retryCount));
+
+        if (action == RuleErrorAction.Branch && branch is null)
+            throw new ArgumentException("A branch policy requires a ruleset to branch to.", nameof(branch));
+
+        _action = action;
+        _retryCount = retryCount;
+        _branch = branch;
+        _logger =
+            (ILogger?)loggerFactory?.CreateLogger<ErrorPolicyRuleAdapter<T>>()
+            ?? NullLogger.Instance;
+    }
+
+    /// <summary>
+    /// Gets the name of the adapted rule.
+    /// </summary>
+    /// <value>The name of the underlying rule.</value>
+    public string Name => _rule.Name;
+
+    /// <summary>
+    /// Evaluates the rule, handling any exception it raises according to the error policy.
+    /// </summary>
+    /// <param name="data">The input data to evaluate.</param>
+    /// <param name="rootContext">The ruleset execution context for tracking evaluation state.</param>
+    /// <returns>
+    /// The result of the rule, <c>false</c> when a failing rule is handled with <see cref="RuleErrorAction.Fail"/>,
+    /// <c>true</c> when it is skipped, or the result of the branch ruleset for <see cref="RuleErrorAction.Branch"/>.
+    /// </returns>
+    /// <remarks>
+    /// <para>The handled error is recorded on the rule's <see cref="RuleContext"/> when it is evaluated within a ruleset,
+    /// and cleared again when a retry succeeds.</para>
+    /// <para>With <see cref="RuleErrorAction.Skip"/> the rule context is marked as skipped, and created if the rule
+    /// is evaluated outside a ruleset, and the success and failure logic of the rule are not run.</para>
+    /// <para>With <see cref="RuleErrorAction.Branch"/> the branch ruleset is evaluated into a nested context
+    /// stored under <c>{Name}[Branch]</c>, and its result becomes the result of the rule.</para>
+    /// <para>With <see cref="RuleErrorAction.Retry"/> the last exception propagates once all retries are exhausted.</para>
+    /// <para>An evaluation aborted because it exceeded its <see cref="RunOptions"/> is never handled.</para>
+    /// </remarks>
+    public bool Evaluate(T data, RootContext rootContext)
+    {
+        _handledErrors.Remove(rootContext);
+
+        return Handle(
+            () => _rule.Evaluate(data, rootContext),
+            rootContext,
+            ruleContext =>
+            {
+                switch (_action)
+                {
+                    case RuleErrorAction.Fail:
+                        return false;
+
+                    case RuleErrorAction.Branch:
+                        _handledErrors.AddOrUpdate(rootContext, new HandledError(RuleErrorAction.Branch));
+                        return EvaluateBranch(data, rootContext);
+
+                    default:
+                        if (ruleContext is null)
+                        {
+                            ruleContext = new RuleContext { RuleName = Name };
+                            rootContext.SetChildContext(Name, ruleContext);
+                        }
+
+                        ruleContext.Skipped = true;
+                        ruleContext.Result = true;
+                        _handledErrors.AddOrUpdate(rootContext, new HandledError(RuleErrorAction.Skip));
+                        return true;
+                }
+            });
+    }
+
+    /// <summary>
+    /// Runs the success logic of the rule, or of the branch ruleset when the rule was replaced by it,
+    /// handling any exception it raises according to the error policy.
+    /// </summary>
+    /// <remarks>
+    /// Nothing is run when the rule was skipped. Success logic is not retried, as it may already have changed the data,
+    /// so with <see cref="RuleErrorAction.Retry"/> an exception it raises propagates.
+    /// </remarks>
+    /// <param name="data">The evaluated data.</param>
+    /// <param name="rootContext">The ruleset execution context.</param>
+    /// <returns>The output of the success logic.</returns>
+    public object Success(T data, RootContext rootContext)
+    {
+        switch (GetHandledAction(rootContext))
+        {
+            case RuleErrorAction.Skip:
+                return true;
+
+            case RuleErrorAction.Branch:
+                return _branch!.Success(data, rootContext);
+
+            default:
+                if (_action == RuleErrorAction.Retry)
+                    return _rule.Success(data, rootContext);
+
+                return Handle(() => _rule.Success(data, rootContext), rootContext, ruleContext => HandleActionError(data, rootContext, ruleContext));
+        }
+    }
+
+    /// <summary>
+    /// Runs the failure logic of the rule, or of the branch ruleset when the rule was replaced by it,
+    /// handling any exception it raises according to the error policy.
+    /// </summary>
+    /// <remarks>
+    /// Nothing is run when the rule was skipped. Failure logic is not retried, as it may already have changed the data,
+    /// so with <see cref="RuleErrorAction.Retry"/> an exception it raises propagates.
+    /// </remarks>
+    /// <param name="data">The evaluated data.</param>
+    /// <param name="rootContext">The ruleset execution context.</param>
+    /// <returns>The output of the failure logic.</returns>
+    public object Failure(T data, RootContext rootContext)
+    {
+        switch (GetHandledAction(rootContext))
+        {
+            case RuleErrorAction.Skip:
+                return false;
+
+            case RuleErrorAction.Branch:
+                return _branch!.Failure(data, rootContext);
+
+            default:
+                if (_action == RuleErrorAction.Retry)
+                    return _rule.Failure(data, rootContext);
+
+                return Handle(() => _rule.Failure(data, rootContext), rootContext, ruleContext => HandleActionError(data, rootContext, ruleContext));
+        }
+    }
+
+    /// <summary>
+    /// Runs <paramref name="attempt"/>, retrying it or handing a raised exception to <paramref name="onError"/>
+    /// according to the error policy.
+    /// </summary>
+    private TResult Handle<TResult>(Func<TResult> attempt, RootContext rootContext, Func<RuleContext?, TResult> onError)
+    {
+        var attempts = 0;
+
+        while (true)
+        {
+            try
+            {
+                var result = attempt();
+
+                // A retry succeeded, so the error of the earlier attempt no longer applies
+                if (attempts > 0 && GetRuleContext(rootContext) is { } retriedContext)
+                    retriedContext.Error = null;
+
+                return result;
+            }
+            catch (Exception ex) when (!rootContext.BudgetExceeded && (_action != RuleErrorAction.Retry || attempts < _retryCount))
+            {
+                attempts++;
+
+                // The error no longer aborts the evaluation, so it must not be reported as its failure
+                rootContext.ClearFailure(ex);
+
+                _logger.LogWarning(
+                    ex,
+                    "[Error] Rule={RuleName} Action={ErrorAction} Attempt={Attempt}",
+                    Name,
+                    _action,
+                    attempts);
+
+                var ruleContext = GetRuleContext(rootContext);
+                if (ruleContext is not null)
+                    ruleContext.Error = ex.Message;
+
+                if (_action == RuleErrorAction.Retry)
+                    continue;
+
+                return onError(ruleContext);
+            }
+        }
+    }
+
+    /// <summary>
+    /// Handles an exception raised by the success or failure logic once the rule has already been recorded as evaluated.
+    /// </summary>
+    private object HandleActionError(T data, RootContext rootContext, RuleContext? ruleContext)
+    {
+        switch (_action)
+        {
+            case RuleErrorAction.Fail:
+                if (ruleContext is not null)
+                    ruleContext.Result = false;
+
+                return false;
+
+            case RuleErrorAction.Branch:
+                var passed = EvaluateBranch(data, rootContext);
+                if (ruleContext is not null)
+                    ruleContext.Result = passed;
+
+                return passed;
+
+            default:
+                if (ruleContext is not null)
+                {
+                    ruleContext.Skipped = true;
+                    ruleContext.Result = true;
+                }
+
+                return true;
+        }
+    }
+
+    private bool EvaluateBranch(T data, RootContext rootContext)
+    {
+        var branchContext = rootContext.CreateNestedContext();
+        rootContext.SetChildContext($"{Name}[Branch]", branchContext);
+
+        return _branch!.Evaluate(data, branchContext);
+    }
+
+    private RuleErrorAction? GetHandledAction(RootContext rootContext)
+        => _handledErrors.TryGetValue(rootContext, out var handledError) ? handledError.Action : null;
+
+    private RuleContext? GetRuleContext(RootContext rootContext)
+        => rootContext.ChildContexts.TryGetValue(Name, out var context) ? context as RuleContext : null;
+
+    private sealed record HandledError(RuleErrorAction Action);
+}
@@ -0,0 +1,100 @@
+using Microsoft.Extensions.Logging;
+using Microsoft.Extensions.Logging.Abstractions;
+using Winterflood.RuleEngine.Engine.Context;
+using Winterflood.RuleEngine.Engine.Rule;
+
+namespace Winterflood.RuleEngine.Engine.Adapter;
+
+/// <summary>
+/// Adapts an <see cref="IRule{T}"/> so that it is only evaluated when a guard condition holds.
+/// When the guard does not hold, the rule is not evaluated and its context is marked as skipped.
+/// </summary>
+/// <remarks>
+/// The guard is enforced by <see cref="Evaluate"/> itself, so it still holds when the guarded rule is
+/// wrapped again, for example by an error policy, a binding or a collection adapter.
+/// </remarks>
+/// <typeparam name="T">The type of data the rule evaluates.</typeparam>
+public class GuardedRuleAdapter<T> : IGuardedRule<T> where T : class
+{
+    private readonly IRule<T> _rule;
+    private readonly Func<T, RootContext, bool> _guard;
+    private readonly ILogger _logger;
+
+    /// <summary>
+    /// Initializes a new instance of the <see cref="GuardedRuleAdapter{T}"/> class.
+    /// </summary>
+    /// <param name="rule">The rule to guard.</param>
+    /// <param name="guard">The condition that must hold for the rule to be evaluated.</param>
+    /// <param name="loggerFactory">Optional logger factory for creating loggers.</param>
+    /// <exception cref="ArgumentNullException">
+    /// Thrown when <paramref name="rule"/> or <paramref name="guard"/> is null.
+    /// </exception>
+    public GuardedRuleAdapter(IRule<T> rule, Func<T, RootContext, bool> guard, ILoggerFactory? loggerFactory = null)
+    {
+        _rule = rule ?? throw new ArgumentNullException(nameof(rule));
+        _guard = guard ?? throw new ArgumentNullException(nameof(guard));
+        _logger =
+            (ILogger?)loggerFactory?.CreateLogger<GuardedRuleAdapter<T>>()
+            ?? NullLogger.Instance;
+    }
+
+    /// <summary>
+    /// Gets the name of the guarded rule.
+    /// </summary>
+    /// <value>The name of the underlying rule.</value>
+    public string Name => _rule.Name;
+
+    /// <summary>
+    /// Evaluates the guard condition for the provided data.
+    /// </summary>
+    /// <param name="data">The input data.</param>
+    /// <param name="rootContext">The ruleset execution context.</param>
+    /// <returns><c>true</c> if the underlying rule should be evaluated; otherwise, <c>false</c>.</returns>
+    public bool Guard(T data, RootContext rootContext)
+    {
+        var result = _guard(data, rootContext);
+
+        _logger.LogInformation(
+            "[Guard] Rule={RuleName} Result={Result}",
+            Name,
+            result ? "EVALUATE" : "SKIP");
+
+        return result;
+    }
+
+    /// <summary>
+    /// Evaluates the underlying rule when the guard holds.
+    /// </summary>
+    /// <remarks>
+    /// When the guard does not hold, the rule context stored under <see cref="Name"/> is marked as skipped,
+    /// and created if the rule is evaluated outside a ruleset, for example for an item of a collection.
+    /// </remarks>
+    /// <param name="data">The input data.</param>
+    /// <param name="rootContext">The ruleset execution context.</param>
+    /// <returns>
+    /// <c>true</c> if the guard does not hold; otherwise, the result of evaluating the underlying rule.
+    /// </returns>
+    public bool Evaluate(T data, RootContext rootContext)
+    {
+        if (Guard(data, rootContext))
+            return _rule.Evaluate(data, rootContext);
+
+        if (rootContext.ChildContexts.GetValueOrDefault(Name) is not RuleContext ruleContext)
+        {
+            ruleContext = new RuleContext { RuleName = Name };
+            rootContext.SetChildContext(Name, ruleContext);
+        }
+
+        ruleContext.Skipped = true;
+        ruleContext.Result = true;
+        return true;
+    }
+
+    /// <inheritdoc/>
+    public object Success(T data, RootContext rootContext)
+        => _rule.Success(data, rootContext);
+
+    /// <inheritdoc/>
+    public object Failure(T data, RootContext rootContext)
+        => _rule.Failure(data, rootContext);
+}
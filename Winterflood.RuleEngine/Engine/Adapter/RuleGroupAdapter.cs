@@ -0,0 +1,200 @@
+using System.Reflection;
+using System.Runtime.ExceptionServices;
+using System.Text.Json;
+using System.Text.Json.Nodes;
+using Microsoft.Extensions.Logging;
+using Microsoft.Extensions.Logging.Abstractions;
+using Winterflood.RuleEngine.Engine.Context;
+using Winterflood.RuleEngine.Engine.Data;
+using Winterflood.RuleEngine.Engine.Rule;
+using Winterflood.RuleEngine.Engine.RuleSet;
+using Winterflood.RuleEngine.Extensions;
+
+namespace Winterflood.RuleEngine.Engine.Adapter;
+
+/// <summary>
+/// Adapts a group of independent <see cref="IRuleSet{T}"/> instances to be used as a single <see cref="IRule{T}"/>.
+/// Every ruleset in the group is evaluated against the same data and the outcomes are combined
+/// according to the configured <see cref="RuleGroupMode"/>.
+/// </summary>
+/// <typeparam name="T">
+/// The type of rule data that the rulesets operate on.
+/// Must be a class implementing <see cref="IRuleData"/> with a parameterless constructor.
+/// </typeparam>
+public class RuleGroupAdapter<T> : IRule<T> where T : class, IRuleData, new()
+{
+    private readonly IReadOnlyList<IRuleSet<T>> _rulesets;
+    private readonly RuleGroupMode _mode;
+    private readonly bool _runInParallel;
+    private readonly ILogger _logger;
+
+    /// <summary>
+    /// Initializes a new instance of the <see cref="RuleGroupAdapter{T}"/> class.
+    /// </summary>
+    /// <param name="name">The name of the group.</param>
+    /// <param name="rulesets">The independent rulesets that make up the group.</param>
+    /// <param name="mode">How the outcomes of the rulesets are combined.</param>
+    /// <param name="runInParallel">Whether the rulesets are evaluated in parallel.</param>
+    /// <param name="loggerFactory">Optional logger factory for creating loggers.</param>
+    /// <exception cref="ArgumentNullException">Thrown when <paramref name="name"/> or <paramref name="rulesets"/> is null.</exception>
+    public RuleGroupAdapter(
+        string name,
+        IEnumerable<IRuleSet<T>> rulesets,
+        RuleGroupMode mode,
+        bool runInParallel = false,
+        ILoggerFactory? loggerFactory = null)
+    {
+        Name = name ?? throw new ArgumentNullException(nameof(name));
+        _rulesets = rulesets?.ToList() ?? throw new ArgumentNullException(nameof(rulesets));
+        _mode = mode;
+        _runInParallel = runInParallel;
+        _logger =
+            (ILogger?)loggerFactory?.CreateLogger<RuleGroupAdapter<T>>()
+            ?? NullLogger.Instance;
+    }
+
+    /// <summary>
+    /// Gets the name of the group.
+    /// </summary>
+    public string Name { get; }
+
+    /// <summary>
+    /// Evaluates every ruleset in the group against the provided data.
+    /// </summary>
+    /// <param name="data">The input data to evaluate.</param>
+    /// <param name="rootContext">The ruleset execution context for tracking evaluation state.</param>
+    /// <returns>
+    /// <c>true</c> if the combined outcome of the group passes according to its <see cref="RuleGroupMode"/>;
+    /// otherwise, <c>false</c>.
+    /// </returns>
+    /// <remarks>
+    /// <para>All rulesets are always evaluated so that each one is present in the audit trail, regardless of the mode.</para>
+    /// <para>When running in parallel each ruleset is evaluated against its own copy of the data and does not see
+    /// the changes made by the others. Once every ruleset completes, their changes are applied to the data in the order
+    /// the rulesets were added, so a member written by several rulesets takes the value written by the last of them.</para>
+    /// <para>When rulesets raise errors, the error of the first of them in the order they were added is rethrown.</para>
+    /// <para>The outcome of each ruleset is stored in a <see cref="RuleGroupContext"/> keyed by the group name.</para>
+    /// </remarks>
+    public bool Evaluate(T data, RootContext rootContext)
+    {
+        _logger.LogInformation(
+            "[Evaluating] RuleGroup={RuleName} Mode={RuleGroupMode} Parallel={Parallel}",
+            Name,
+            _mode,
+            _runInParallel);
+
+        var groupContext = new RuleGroupContext(rootContext) { Mode = _mode };
+        var nestedContexts = _rulesets.Select(_ => rootContext.CreateNestedContext()).ToArray();
+        var results = new bool[_rulesets.Count];
+
+        if (_runInParallel)
+        {
+            EvaluateInParallel(data, nestedContexts, results);
+        }
+        else
+        {
+            for (var i = 0; i < _rulesets.Count; i++)
+                results[i] = _rulesets[i].Evaluate(data, nestedContexts[i]);
+        }
+
+        var result = _mode switch
+        {
+            RuleGroupMode.All => results.All(passed => passed),
+            RuleGroupMode.Any => results.Any(passed => passed),
+            _ => throw new InvalidOperationException($"Unsupported rule group mode: {_mode}")
+        };
+
+        // Nested contexts are attached once evaluation completes so the group context is never written concurrently
+        groupContext.Result = result;
+        for (var i = 0; i < _rulesets.Count; i++)
+            groupContext.SetChildContext(_rulesets[i].Name, nestedContexts[i]);
+
+        rootContext.SetChildContext(Name, groupContext);
+
+        _logger.LogInformation(
+            "[Evaluated] RuleGroup={RuleName} Result={Result} RuleSets={RuleSetCount}",
+            Name,
+            result ? "PASSED" : "FAILED",
+            _rulesets.Count);
+
+        return result;
+    }
+
+    private void EvaluateInParallel(T data, RootContext[] nestedContexts, bool[] results)
+    {
+        var original = (JsonObject)JsonSerializer.SerializeToNode(data)!;
+        var copies = _rulesets.Select(_ => data.Clone()!).ToArray();
+        var errors = new Exception?[_rulesets.Count];
+
+        Parallel.For(0, _rulesets.Count, i =>
+        {
+            try
+            {
+                results[i] = _rulesets[i].Evaluate(copies[i], nestedContexts[i]);
+            }
+            catch (Exception ex)
+            {
+                errors[i] = ex;
+            }
+        });
+
+        if (errors.FirstOrDefault(ex => ex is not null) is { } error)
+            ExceptionDispatchInfo.Throw(error);
+
+        // Changes are applied in the order the rulesets were added so the merged data does not depend on scheduling
+        var merged = (JsonObject)original.DeepClone();
+        foreach (var copy in copies)
+            MergeChanges(original, (JsonObject)JsonSerializer.SerializeToNode(copy)!, merged);
+
+        CopyMembers(merged.Deserialize<T>()!, data);
+    }
+
+    private static void MergeChanges(JsonObject original, JsonObject changed, JsonObject merged)
+    {
+        foreach (var (key, value) in changed)
+        {
+            var before = original[key];
+            if (JsonNode.DeepEquals(before, value))
+                continue;
+
+            if (before is JsonObject beforeObject && value is JsonObject valueObject && merged[key] is JsonObject mergedObject)
+                MergeChanges(beforeObject, valueObject, mergedObject);
+            else
+                merged[key] = value?.DeepClone();
+        }
+    }
+
+    private static void CopyMembers(T source, T target)
+    {
+        var properties = typeof(T)
+            .GetProperties(BindingFlags.Public | BindingFlags.Instance)
+            .Where(property => property is { CanRead: true, CanWrite: true } && property.GetIndexParameters().Length == 0);
+
+        foreach (var property in properties)
+            property.SetValue(target, property.GetValue(source));
+    }
+
+    /// <summary>
+    /// Handles successful evaluation of the group.
+    /// </summary>
+    /// <param name="input">The evaluated input data.</param>
+    /// <param name="rootContext">The ruleset execution context containing all group evaluations.</param>
+    /// <returns>Returns <c>true</c>.</returns>
+    public object Success(T input, RootContext rootContext)
+    {
+        _logger.LogInformation("[Success] RuleGroup={RuleName}", Name);
+        return true;
+    }
+
+    /// <summary>
+    /// Handles failed evaluation of the group.
+    /// </summary>
+    /// <param name="input">The evaluated input data.</param>
+    /// <param name="rootContext">The ruleset execution context containing all group evaluations.</param>
+    /// <returns>Returns <c>false</c>.</returns>
+    public object Failure(T input, RootContext rootContext)
+    {
+        _logger.LogInformation("[Failure] RuleGroup={RuleName}", Name);
+        return false;
+    }
+}
@@ -59,12 +59,19 @@ public class RulesetAsRuleAdapter<T> : IRule<T> where T : class, IRuleData, new(
     {
         _logger.LogInformation("[Evaluating] RulesetAsRule={RuleName}", Name);
 
-        var nestedContext = new RootContext();
-        
-        // Execute nested ruleset
-        var result = _ruleset.Evaluate(data, nestedContext);
+        var nestedContext = rootContext.CreateNestedContext();
+        bool result;
 
-        rootContext.SetChildContext(_ruleset.Name, nestedContext);
+        try
+        {
+            // Execute nested ruleset
+            result = _ruleset.Evaluate(data, nestedContext);
+        }
+        finally
+        {
+            // Keep the nested evaluation trail even when the ruleset raised an error
+            rootContext.SetChildContext(_ruleset.Name, nestedContext);
+        }
 
         _logger.LogInformation(
             "[Evaluated] RulesetAsRule={RuleName} Result={Result}",
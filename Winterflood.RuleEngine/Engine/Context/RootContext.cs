@@ -1,3 +1,4 @@
+using System.Collections.Concurrent;
 using System.Text.Json.Serialization;
 
 namespace Winterflood.RuleEngine.Engine.Context;
@@ -11,12 +12,203 @@ public class RootContext
     /// <summary>
     /// Dictionary storing execution contexts for rules evaluated within this ruleset.
     /// The key is the rule name, and the value is the execution context of that rule.
+    /// Evaluation only adds and updates entries, never removes them, so they are enumerated in the order they were
+    /// first stored, which is the order the rules were evaluated, and identical inputs produce identical audit trails.
     /// </summary>
     [JsonInclude]
     public readonly Dictionary<string, object?> ChildContexts = new();
 
+    /// <summary>
+    /// Caller supplied data, keyed by type, shared with every nested context created from this context.
+    /// Nested contexts of parallel groups read it concurrently, so it is safe to access from several threads.
+    /// </summary>
+    private readonly ConcurrentDictionary<Type, object> _data;
+
+    /// <summary>
+    /// The unhandled error that aborted the evaluation, shared with every nested context created from this context.
+    /// </summary>
+    private readonly Failure _failure;
+
+    /// <summary>
+    /// The limits of the evaluation and the work done so far, shared with every nested context created from this context.
+    /// </summary>
+    private readonly Budget _budget;
+
+    /// <summary>
+    /// How deeply this context is nested within the context the evaluation started in.
+    /// </summary>
+    private readonly int _depth;
+
+    /// <summary>
+    /// Initializes a new instance of the <see cref="RootContext"/> class for a new evaluation.
+    /// </summary>
+    public RootContext()
+        : this(new ConcurrentDictionary<Type, object>(), new Failure(), new Budget(), 0)
+    {
+    }
+
+    /// <summary>
+    /// Initializes a new instance of the <see cref="RootContext"/> class for a nested evaluation
+    /// that shares the caller supplied data, options and failure of <paramref name="parent"/>.
+    /// </summary>
+    /// <param name="parent">The context the nested evaluation is part of.</param>
+    protected RootContext(RootContext parent)
+        : this(parent._data, parent._failure, parent._budget, parent._depth + 1)
+    {
+    }
+
+    private RootContext(ConcurrentDictionary<Type, object> data, Failure failure, Budget budget, int depth)
+    {
+        _data = data;
+        _failure = failure;
+        _budget = budget;
+        _depth = depth;
+    }
+
+    /// <summary>
+    /// Attaches caller supplied data, such as a per-request service or cache, that rules can retrieve
+    /// with <see cref="GetData{T}"/>. Any data previously attached with the same type is replaced.
+    /// </summary>
+    /// <remarks>
+    /// Data is shared with the nested contexts created by adapters, so it should be attached before evaluation starts
+    /// and not replaced while rules are being evaluated.
+    /// </remarks>
+    /// <typeparam name="T">The type the data is retrieved by.</typeparam>
+    /// <param name="value">The data to attach.</param>
+    /// <returns>This context, to allow chaining.</returns>
+    public RootContext WithData<T>(T value) where T : class
+    {
+        _data[typeof(T)] = value ?? throw new ArgumentNullException(nameof(value));
+        return this;
+    }
+
+    /// <summary>
+    /// Retrieves caller supplied data previously attached with <see cref="WithData{T}"/>.
+    /// </summary>
+    /// <typeparam name="T">The type the data was attached as.</typeparam>
+    /// <returns>The attached data if found; otherwise, <c>null</c>.</returns>
+    public T? GetData<T>() where T : class
+    {
+        return _data.TryGetValue(typeof(T), out var value) ? (T)value : null;
+    }
+
+    /// <summary>
+    /// Limits the work the evaluation recorded into this context may do.
+    /// </summary>
+    /// <remarks>
+    /// The options are shared with the nested contexts created by adapters, so they should be set before evaluation starts.
+    /// </remarks>
+    /// <param name="options">The limits to apply.</param>
+    /// <returns>This context, to allow chaining.</returns>
+    public RootContext WithOptions(RunOptions options)
+    {
+        _budget.Options = options ?? throw new ArgumentNullException(nameof(options));
+        return this;
+    }
+
+    /// <summary>
+    /// Creates an empty context for a nested evaluation that shares this context's caller supplied data and options.
+    /// </summary>
+    /// <returns>A new context sharing the data and options attached to this context.</returns>
+    public RootContext CreateNestedContext()
+    {
+        return new RootContext(this);
+    }
+
+    /// <summary>
+    /// Gets whether the evaluation was aborted because it exceeded the limits set with <see cref="WithOptions"/>.
+    /// </summary>
+    internal bool BudgetExceeded => _budget.Exceeded;
+
+    /// <summary>
+    /// Counts the evaluation of a rule of a ruleset against the limits set with <see cref="WithOptions"/>.
+    /// </summary>
+    /// <param name="ruleSetName">The name of the ruleset evaluating the rule.</param>
+    /// <exception cref="InvalidOperationException">Thrown when the evaluation exceeds its limits.</exception>
+    internal void CountStep(string ruleSetName)
+    {
+        var options = _budget.Options;
+        if (options is null)
+            return;
+
+        var exceeded = GetExceededLimit(options, Interlocked.Increment(ref _budget.Steps), ruleSetName);
+        if (exceeded is null)
+            return;
+
+        _budget.Exceeded = true;
+        throw new InvalidOperationException($"Evaluation budget exceeded: {exceeded}.");
+    }
+
+    private string? GetExceededLimit(RunOptions options, int steps, string ruleSetName)
+    {
+        if (options.MaxSteps is { } maxSteps && steps > maxSteps)
+            return $"more than {maxSteps} rules were evaluated";
+
+        if (options.Deadline is { } deadline && options.TimeProvider.GetUtcNow() > deadline)
+            return $"the deadline {deadline:O} has passed";
+
+        if (options.MaxNestingDepth is { } maxDepth && _depth > maxDepth)
+            return $"RuleSet '{ruleSetName}' is nested {_depth} levels deep, more than {maxDepth}";
+
+        return null;
+    }
+
+    /// <summary>
+    /// Gets the context of the rule whose unhandled error aborted the evaluation, or <c>null</c> if no rule raised one.
+    /// </summary>
+    /// <remarks>
+    /// When the error was raised within a nested ruleset, this is the nested rule that raised it rather than
+    /// the rule that evaluated the nested ruleset.
+    /// </remarks>
+    internal RuleContext? FailedRule
+    {
+        get
+        {
+            lock (_failure)
+                return _failure.RuleContext;
+        }
+    }
+
+    /// <summary>
+    /// Records the rule that raised an unhandled error. An error rethrown by an enclosing ruleset keeps
+    /// the rule it was first recorded against, even when rulesets evaluated in parallel recorded other errors in between.
+    /// </summary>
+    /// <param name="exception">The unhandled error.</param>
+    /// <param name="ruleContext">The context of the rule being evaluated when the error was raised.</param>
+    internal void RecordFailure(Exception exception, RuleContext ruleContext)
+    {
+        lock (_failure)
+        {
+            if (!_failure.Recorded.TryGetValue(exception, out var first))
+                _failure.Recorded[exception] = first = ruleContext;
+
+            _failure.Exception = exception;
+            _failure.RuleContext = first;
+        }
+    }
+
+    /// <summary>
+    /// Forgets the rule recorded for an error that was handled after it was recorded, such as an error raised
+    /// within a nested ruleset and handled by the error policy of the rule that evaluated it.
+    /// </summary>
+    /// <param name="exception">The handled error.</param>
+    internal void ClearFailure(Exception exception)
+    {
+        lock (_failure)
+        {
+            _failure.Recorded.Remove(exception);
+
+            if (!ReferenceEquals(_failure.Exception, exception))
+                return;
+
+            _failure.Exception = null;
+            _failure.RuleContext = null;
+        }
+    }
+
     /// <summary>
     /// Adds or updates the execution context for a specific rule.
+    /// An updated context keeps the position the rule was first stored at.
     /// </summary>
     /// <typeparam name="T">The type of the rule's execution context.</typeparam>
     /// <param name="key">The unique key (rule name) identifying the rule.</param>
@@ -52,9 +244,58 @@ public class RootContext
         return ChildContexts.ContainsKey(key);
     }
 
+    /// <summary>
+    /// Returns the contexts of every rule evaluated within this context and its nested contexts, in evaluation order.
+    /// </summary>
+    /// <remarks>
+    /// Each entry records the data before and after the rule together with the changes it made,
+    /// allowing the state of the data to be reconstructed at any step of the evaluation.
+    /// </remarks>
+    /// <returns>The rule contexts in evaluation order.</returns>
+    public List<RuleContext> GetTimeline()
+    {
+        var timeline = new List<RuleContext>();
+        Collect(this);
+        return timeline;
+
+        void Collect(RootContext context)
+        {
+            foreach (var child in context.ChildContexts.Values)
+            {
+                switch (child)
+                {
+                    case RuleContext ruleContext:
+                        timeline.Add(ruleContext);
+                        break;
+                    case RootContext nestedContext:
+                        Collect(nestedContext);
+                        break;
+                }
+            }
+        }
+    }
+
     /// <inheritdoc />
     public override string ToString()
     {
         return ChildContexts.Select(x => x.Value?.ToString() ?? "null").Aggregate((a, b) => $"{a}, {b}");
     }
+
+    private sealed class Failure
+    {
+        public Exception? Exception { get; set; }
+        public RuleContext? RuleContext { get; set; }
+
+        // The rule each error was first recorded against, as errors are rethrown by every enclosing ruleset
+        public Dictionary<Exception, RuleContext> Recorded { get; } = new(ReferenceEqualityComparer.Instance);
+    }
+
+    private sealed class Budget
+    {
+        public int Steps;
+        public RunOptions? Options { get; set; }
+
+        // Set by whichever nested context exceeds the budget, possibly on another thread of a parallel group
+        public volatile bool Exceeded;
+    }
 }
@@ -26,12 +26,29 @@ public class RuleContext
     /// </summary>
     public object? RuleDataAfterEvaluation { get; set; }
 
+    /// <summary>
+    /// The members of the data object that were changed by the rule evaluation.
+    /// Computed from <see cref="RuleDataBeforeEvaluation"/> and <see cref="RuleDataAfterEvaluation"/>.
+    /// </summary>
+    public List<RuleDataChange> Changes { get; set; } = [];
+
     /// <summary>
     /// Indicates whether the rule evaluation was successful.
     /// True if the rule passed, false otherwise.
     /// </summary>
     public bool Result { get; set; }
 
+    /// <summary>
+    /// Indicates whether the rule was skipped because its guard condition did not hold.
+    /// Skipped rules are not evaluated and do not affect the ruleset result.
+    /// </summary>
+    public bool Skipped { get; set; }
+
+    /// <summary>
+    /// The message of the error raised while evaluating the rule, if any.
+    /// </summary>
+    public string? Error { get; set; }
+
     /// <summary>
     /// The final computed output of the rule, if applicable.
     /// This could be any object or value returned by the rule execution.
@@ -40,5 +57,5 @@ public class RuleContext
 
     /// <inheritdoc />
     public override string ToString()
-        => $"RuleName={RuleName}, RuleDataBeforeEvaluation={RuleDataBeforeEvaluation}, RuleDataAfterEvaluation={RuleDataAfterEvaluation}, Result={Result}";
+        => $"RuleName={RuleName}, RuleDataBeforeEvaluation={RuleDataBeforeEvaluation}, RuleDataAfterEvaluation={RuleDataAfterEvaluation}, Result={Result}, Skipped={Skipped}, Error={Error}";
 }
\ No newline at end of file
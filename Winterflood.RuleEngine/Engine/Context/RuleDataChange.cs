@@ -0,0 +1,83 @@
+using System.Text.Json;
+using System.Text.Json.Nodes;
+
+namespace Winterflood.RuleEngine.Engine.Context;
+
+/// <summary>
+/// Represents a single member of the rule data that was changed by a rule.
+/// </summary>
+public class RuleDataChange
+{
+    /// <summary>
+    /// The path of the changed member, using dots for nested members and brackets for list items
+    /// (for example <c>Customer.IsHighValue</c> or <c>Items[2].Price</c>).
+    /// </summary>
+    public string Path { get; set; } = string.Empty;
+
+    /// <summary>
+    /// The JSON value of the member before the rule was evaluated, or <c>null</c> if it did not exist.
+    /// </summary>
+    public JsonNode? Before { get; set; }
+
+    /// <summary>
+    /// The JSON value of the member after the rule was evaluated, or <c>null</c> if it no longer exists.
+    /// </summary>
+    public JsonNode? After { get; set; }
+
+    /// <summary>
+    /// Compares two snapshots of rule data and returns the members that differ.
+    /// </summary>
+    /// <param name="before">The data before evaluation.</param>
+    /// <param name="after">The data after evaluation.</param>
+    /// <returns>The changed members, ordered by their position in the data.</returns>
+    public static List<RuleDataChange> Compare(object? before, object? after)
+    {
+        var changes = new List<RuleDataChange>();
+        Compare(ToNode(before), ToNode(after), string.Empty, changes);
+        return changes;
+    }
+
+    private static JsonNode? ToNode(object? value)
+        => value is null ? null : JsonSerializer.SerializeToNode(value, value.GetType());
+
+    private static void Compare(JsonNode? before, JsonNode? after, string path, List<RuleDataChange> changes)
+    {
+        switch (before, after)
+        {
+            case (JsonObject beforeObject, JsonObject afterObject):
+                var keys = beforeObject.Select(p => p.Key).Union(afterObject.Select(p => p.Key));
+                foreach (var key in keys)
+                {
+                    Compare(
+                        beforeObject[key],
+                        afterObject[key],
+                        path.Length == 0 ? key : $"{path}.{key}",
+                        changes);
+                }
+                break;
+
+            case (JsonArray beforeArray, JsonArray afterArray):
+                for (var i = 0; i < Math.Max(beforeArray.Count, afterArray.Count); i++)
+                {
+                    Compare(
+                        i < beforeArray.Count ? beforeArray[i] : null,
+                        i < afterArray.Count ? afterArray[i] : null,
+                        $"{path}[{i}]",
+                        changes);
+                }
+                break;
+
+            default:
+                if (!JsonNode.DeepEquals(before, after))
+                {
+                    changes.Add(new RuleDataChange
+                    {
+                        Path = path,
+                        Before = before?.DeepClone(),
+                        After = after?.DeepClone()
+                    });
+                }
+                break;
+        }
+    }
+}
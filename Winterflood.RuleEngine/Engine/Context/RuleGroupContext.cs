@@ -0,0 +1,19 @@
+namespace Winterflood.RuleEngine.Engine.Context;
+
+/// <summary>
+/// Represents the execution context for a group of rulesets evaluated together.
+/// Stores the nested context of each ruleset alongside the combined outcome of the group.
+/// </summary>
+/// <param name="parent">The context the group is evaluated in, whose caller supplied data, options and failure the group shares.</param>
+public class RuleGroupContext(RootContext parent) : RootContext(parent)
+{
+    /// <summary>
+    /// The mode used to combine the outcomes of the rulesets in the group.
+    /// </summary>
+    public RuleGroupMode Mode { get; set; }
+
+    /// <summary>
+    /// Indicates whether the group passed according to its <see cref="Mode"/>.
+    /// </summary>
+    public bool Result { get; set; }
+}
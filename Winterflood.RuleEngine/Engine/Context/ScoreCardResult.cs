@@ -0,0 +1,47 @@
+namespace Winterflood.RuleEngine.Engine.Context;
+
+/// <summary>
+/// Represents the outcome of a scorecard rule, recorded as the <see cref="RuleContext.Output"/> of its context.
+/// Records the total score, the threshold it was compared against, and the contribution of each factor.
+/// </summary>
+public class ScoreCardResult
+{
+    /// <summary>
+    /// The sum of the weights of every factor whose condition held.
+    /// </summary>
+    public decimal Score { get; set; }
+
+    /// <summary>
+    /// The score required for the scorecard to pass.
+    /// </summary>
+    public decimal Threshold { get; set; }
+
+    /// <summary>
+    /// The outcome of each factor, in the order the factors were added.
+    /// </summary>
+    public List<ScoreCardFactorResult> Factors { get; set; } = [];
+
+    /// <inheritdoc />
+    public override string ToString() => $"Score={Score}, Threshold={Threshold}";
+}
+
+/// <summary>
+/// Represents the outcome of a single scorecard factor.
+/// </summary>
+public class ScoreCardFactorResult
+{
+    /// <summary>
+    /// The name of the factor.
+    /// </summary>
+    public string Name { get; set; } = string.Empty;
+
+    /// <summary>
+    /// Indicates whether the factor's condition held.
+    /// </summary>
+    public bool Matched { get; set; }
+
+    /// <summary>
+    /// The weight the factor contributed to the score; zero when the condition did not hold.
+    /// </summary>
+    public decimal Points { get; set; }
+}
@@ -1,4 +1,5 @@
 using Winterflood.RuleEngine.Engine.Adapter;
+using Winterflood.RuleEngine.Engine.Context;
 using Winterflood.RuleEngine.Engine.Data;
 using Winterflood.RuleEngine.Engine.Rule;
 using Winterflood.RuleEngine.Engine.RuleSet;
@@ -92,4 +93,77 @@ public static class RuleExtensions
         where TSource : class, IRuleData, new()
         where TTarget : class
         => new RuleBinder<TSource, TTarget>(rule, bindingFactory, afterExecute);
+
+    /// <summary>
+    /// Guards a rule so that it is only evaluated when the guard condition holds.
+    /// </summary>
+    /// <typeparam name="T">The type of rule data.</typeparam>
+    /// <param name="rule">The rule to guard.</param>
+    /// <param name="guard">The condition that must hold for the rule to be evaluated.</param>
+    /// <returns>
+    /// An <see cref="IRule{T}"/> that is skipped, and recorded as skipped, when the guard does not hold.
+    /// The guard is kept when the returned rule is wrapped again.
+    /// </returns>
+    /// <example>
+    /// <code>
+    /// var discountRule = loyaltyDiscount.WithGuard((order, _) => order.Customer.IsMember);
+    /// </code>
+    /// </example>
+    public static IRule<T> WithGuard<T>(this IRule<T> rule, Func<T, RootContext, bool> guard)
+        where T : class
+        => new GuardedRuleAdapter<T>(rule, guard);
+
+    /// <summary>
+    /// Applies an error policy to a rule so that exceptions raised while evaluating it do not abort the ruleset.
+    /// </summary>
+    /// <typeparam name="T">
+    /// The type of rule data. Must be a class implementing <see cref="IRuleData"/>
+    /// with a parameterless constructor.
+    /// </typeparam>
+    /// <param name="rule">The rule to apply the error policy to.</param>
+    /// <param name="action">How an exception raised by the rule is handled.</param>
+    /// <param name="retryCount">The number of additional attempts made for <see cref="RuleErrorAction.Retry"/>.</param>
+    /// <param name="branch">The ruleset evaluated in place of the rule for <see cref="RuleErrorAction.Branch"/>.</param>
+    /// <returns>An <see cref="IRule{T}"/> that handles evaluation errors according to the policy.</returns>
+    /// <example>
+    /// <code>
+    /// var lookupRule = creditLookup.OnError(RuleErrorAction.Retry, retryCount: 3);
+    /// var fallbackRule = creditLookup.OnError(RuleErrorAction.Branch, branch: manualReviewRuleset);
+    /// </code>
+    /// </example>
+    public static IRule<T> OnError<T>(
+        this IRule<T> rule,
+        RuleErrorAction action,
+        int retryCount = 0,
+        IRuleSet<T>? branch = null)
+        where T : class, IRuleData, new()
+        => new ErrorPolicyRuleAdapter<T>(rule, action, retryCount, branch);
+
+    /// <summary>
+    /// Combines independent rulesets into a single rule whose outcome is derived from all of them.
+    /// </summary>
+    /// <typeparam name="T">
+    /// The type of rule data. Must be a class implementing <see cref="IRuleData"/>
+    /// with a parameterless constructor.
+    /// </typeparam>
+    /// <param name="rulesets">The independent rulesets to group.</param>
+    /// <param name="name">The name of the group.</param>
+    /// <param name="mode">How the outcomes of the rulesets are combined.</param>
+    /// <param name="runInParallel">Whether the rulesets are evaluated in parallel.</param>
+    /// <returns>
+    /// An <see cref="IRule{T}"/> that evaluates every ruleset and combines their outcomes.
+    /// </returns>
+    /// <example>
+    /// <code>
+    /// var riskChecks = new[] { fraudRuleset, sanctionsRuleset }
+    ///     .AsGroup("RiskChecks", RuleGroupMode.All, runInParallel: true);
+    /// </code>
+    /// </example>
+    public static IRule<T> AsGroup<T>(
+        this IEnumerable<IRuleSet<T>> rulesets,
+        string name,
+        RuleGroupMode mode,
+        bool runInParallel = false)
+        where T : class, IRuleData, new()
+        => new RuleGroupAdapter<T>(name, rulesets, mode, runInParallel);
 }
\ No newline at end of file
@@ -0,0 +1,22 @@
+using Winterflood.RuleEngine.Engine.Context;
+
+namespace Winterflood.RuleEngine.Engine.Rule;
+
+/// <summary>
+/// Defines a rule that is only evaluated when its guard condition holds.
+/// Rules whose guard does not hold are not evaluated and are recorded as skipped.
+/// </summary>
+/// <typeparam name="TData">The input data type that the rule operates on.</typeparam>
+public interface IGuardedRule<in TData> : IRule<TData>
+    where TData : class
+{
+    /// <summary>
+    /// Determines whether the rule should be evaluated for the provided input data.
+    /// </summary>
+    /// <param name="input">The input data to evaluate.</param>
+    /// <param name="rootContext">The ruleset context used to store rule execution details.</param>
+    /// <returns>
+    /// Returns <c>true</c> if the rule should be evaluated; <c>false</c> if it should be skipped.
+    /// </returns>
+    bool Guard(TData input, RootContext rootContext);
+}
@@ -0,0 +1,181 @@
+using System.Runtime.CompilerServices;
+using Microsoft.Extensions.Logging;
+using Microsoft.Extensions.Logging.Abstractions;
+using Winterflood.RuleEngine.Engine.Context;
+
+namespace Winterflood.RuleEngine.Engine.Rule;
+
+/// <summary>
+/// Represents a weighted scoring rule, such as a credit scorecard.
+/// Every factor whose condition holds adds its weight to the score, and the rule passes when the
+/// total score reaches the threshold.
+/// </summary>
+/// <remarks>
+/// Once scored, the score can be assigned to the data with <see cref="AssignScore"/>, and the actions added with
+/// <see cref="OnSuccess"/> and <see cref="OnFailure"/> run when the scorecard passes or fails.
+/// </remarks>
+/// <typeparam name="TData">The input data type that the scorecard operates on.</typeparam>
+public class ScoreCard<TData> : IRule<TData>
+    where TData : class
+{
+    private readonly List<(string Name, Func<TData, RootContext, bool> Condition, decimal Weight)> _factors = [];
+    private readonly ILogger _logger;
+    private Action<TData, decimal>? _assignScore;
+    private Action<TData, RootContext>? _onSuccess;
+    private Action<TData, RootContext>? _onFailure;
+
+    /// <summary>
+    /// The score of the last evaluation in each context, read by the success or failure logic that follows it.
+    /// </summary>
+    private readonly ConditionalWeakTable<RootContext, ScoreCardResult> _results = new();
+
+    /// <summary>
+    /// Creates a scorecard with no factors.
+    /// </summary>
+    /// <param name="name">Unique name of the scorecard.</param>
+    /// <param name="threshold">The score required for the scorecard to pass.</param>
+    /// <param name="loggerFactory">Optional logger factory for creating loggers.</param>
+    public ScoreCard(string name, decimal threshold, ILoggerFactory? loggerFactory = null)
+    {
+        Name = name;
+        Threshold = threshold;
+        _logger =
+            (ILogger?)loggerFactory?.CreateLogger<ScoreCard<TData>>()
+            ?? NullLogger.Instance;
+    }
+
+    /// <summary>
+    /// The unique name of the scorecard.
+    /// </summary>
+    public string Name { get; }
+
+    /// <summary>
+    /// The score required for the scorecard to pass.
+    /// </summary>
+    public decimal Threshold { get; }
+
+    /// <summary>
+    /// Adds a factor to the scorecard.
+    /// </summary>
+    /// <param name="name">The name of the factor, used in the score breakdown.</param>
+    /// <param name="condition">The condition that must hold for the factor to contribute its weight.</param>
+    /// <param name="weight">The points the factor contributes; may be negative.</param>
+    /// <returns>This scorecard, to allow chaining.</returns>
+    public ScoreCard<TData> AddFactor(string name, Func<TData, RootContext, bool> condition, decimal weight)
+    {
+        _factors.Add((name, condition, weight));
+        return this;
+    }
+
+    /// <summary>
+    /// Assigns the score to the data once the scorecard has been evaluated, before its success or failure action runs.
+    /// </summary>
+    /// <param name="assign">The action storing the score in the data.</param>
+    /// <returns>This scorecard, to allow chaining.</returns>
+    public ScoreCard<TData> AssignScore(Action<TData, decimal> assign)
+    {
+        _assignScore = assign;
+        return this;
+    }
+
+    /// <summary>
+    /// Sets the action to perform when the score reaches the threshold.
+    /// </summary>
+    /// <param name="action">The action to perform.</param>
+    /// <returns>This scorecard, to allow chaining.</returns>
+    public ScoreCard<TData> OnSuccess(Action<TData, RootContext> action)
+    {
+        _onSuccess = action;
+        return this;
+    }
+
+    /// <summary>
+    /// Sets the action to perform when the score falls short of the threshold.
+    /// </summary>
+    /// <param name="action">The action to perform.</param>
+    /// <returns>This scorecard, to allow chaining.</returns>
+    public ScoreCard<TData> OnFailure(Action<TData, RootContext> action)
+    {
+        _onFailure = action;
+        return this;
+    }
+
+    /// <summary>
+    /// Scores the input data and compares the total against the threshold.
+    /// </summary>
+    /// <param name="data">The input data to score.</param>
+    /// <param name="rootContext">The ruleset context used for execution tracking.</param>
+    /// <returns>Returns true if the score reaches the threshold, otherwise false.</returns>
+    /// <remarks>
+    /// The score and the breakdown of every factor are returned as a <see cref="ScoreCardResult"/> by
+    /// <see cref="Success"/> or <see cref="Failure"/>, which a ruleset records as the output of the scorecard's context.
+    /// </remarks>
+    public bool Evaluate(TData data, RootContext rootContext)
+    {
+        var factors =
+            _factors
+                .Select(factor =>
+                {
+                    var matched = factor.Condition(data, rootContext);
+                    return new ScoreCardFactorResult
+                    {
+                        Name = factor.Name,
+                        Matched = matched,
+                        Points = matched ? factor.Weight : 0
+                    };
+                })
+                .ToList();
+
+        var score = factors.Sum(factor => factor.Points);
+        var passed = score >= Threshold;
+
+        _results.AddOrUpdate(rootContext, new ScoreCardResult
+        {
+            Score = score,
+            Threshold = Threshold,
+            Factors = factors
+        });
+
+        _logger.LogInformation(
+            "[Evaluated] ScoreCard={RuleName} Score={Score} Threshold={Threshold} Result={Result}",
+            Name,
+            score,
+            Threshold,
+            passed ? "PASSED" : "FAILED");
+
+        return passed;
+    }
+
+    /// <summary>
+    /// Assigns the score to the data and executes the success action when the scorecard passes.
+    /// </summary>
+    /// <param name="data">The input data.</param>
+    /// <param name="rootContext">The ruleset context the scorecard was evaluated in.</param>
+    /// <returns>The score and the breakdown of every factor.</returns>
+    public object Success(TData data, RootContext rootContext)
+    {
+        return Complete(data, rootContext, _onSuccess);
+    }
+
+    /// <summary>
+    /// Assigns the score to the data and executes the failure action when the scorecard fails.
+    /// </summary>
+    /// <param name="data">The input data.</param>
+    /// <param name="rootContext">The ruleset context the scorecard was evaluated in.</param>
+    /// <returns>The score and the breakdown of every factor.</returns>
+    public object Failure(TData data, RootContext rootContext)
+    {
+        return Complete(data, rootContext, _onFailure);
+    }
+
+    private ScoreCardResult Complete(TData data, RootContext rootContext, Action<TData, RootContext>? action)
+    {
+        if (!_results.TryGetValue(rootContext, out var result))
+            throw new InvalidOperationException($"ScoreCard '{Name}' has not been evaluated in this context.");
+
+        _assignScore?.Invoke(data, result.Score);
+        action?.Invoke(data, rootContext);
+
+        return result;
+    }
+}
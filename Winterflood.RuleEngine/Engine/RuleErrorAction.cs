@@ -0,0 +1,28 @@
+namespace Winterflood.RuleEngine.Engine
+{
+    /// <summary>
+    /// Defines how a rule responds to an exception raised while it is being evaluated.
+    /// </summary>
+    public enum RuleErrorAction
+    {
+        /// <summary>
+        /// Treats the rule as failed, running its failure logic.
+        /// </summary>
+        Fail,
+
+        /// <summary>
+        /// Skips the rule so that it does not affect the ruleset result.
+        /// </summary>
+        Skip,
+
+        /// <summary>
+        /// Re-evaluates the rule a configured number of times before letting the exception propagate.
+        /// </summary>
+        Retry,
+
+        /// <summary>
+        /// Evaluates a fallback ruleset in place of the rule, using its result as the result of the rule.
+        /// </summary>
+        Branch
+    }
+}
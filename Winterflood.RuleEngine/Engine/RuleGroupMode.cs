@@ -0,0 +1,18 @@
+namespace Winterflood.RuleEngine.Engine
+{
+    /// <summary>
+    /// Defines how the outcomes of a group of independent rulesets are combined.
+    /// </summary>
+    public enum RuleGroupMode
+    {
+        /// <summary>
+        /// The group passes only when every ruleset in the group passes.
+        /// </summary>
+        All,
+
+        /// <summary>
+        /// The group passes when at least one ruleset in the group passes.
+        /// </summary>
+        Any
+    }
+}
@@ -52,9 +52,56 @@ public class RuleSet<TData>(
             Name,
             ruleExecutionMode);
 
-        foreach (var lazyRule in _rules)
+        var rules = ResolveRules();
+
+        // Tracks the rule being evaluated so that an unhandled error can be recorded against it
+        RuleContext? currentContext = null;
+
+        try
         {
-            var rule = lazyRule.Value;
+            return EvaluateRules(rules, data, rootContext, ref currentContext);
+        }
+        catch (Exception ex) when (currentContext is not null)
+        {
+            currentContext.Result = false;
+            currentContext.Error = ex.Message;
+            rootContext.RecordFailure(ex, currentContext);
+
+            _logger.LogError(
+                ex,
+                "[Rule Error] Rule={RuleName} for RuleSet={RuleSetName}",
+                currentContext.RuleName,
+                Name);
+
+            throw;
+        }
+    }
+
+    /// <summary>
+    /// Creates the rules of the ruleset and checks their names before any of them is evaluated,
+    /// so that a ruleset with conflicting rules runs none of their actions.
+    /// </summary>
+    /// <exception cref="InvalidOperationException">Thrown when two rules share a name.</exception>
+    private List<IRule<TData>> ResolveRules()
+    {
+        var rules = _rules.Select(lazyRule => lazyRule.Value).ToList();
+
+        // Contexts are keyed by rule name, so a repeated name would overwrite an earlier rule's entry
+        var ruleNames = new HashSet<string>();
+        foreach (var rule in rules)
+        {
+            if (!ruleNames.Add(rule.Name))
+                throw new InvalidOperationException($"RuleSet '{Name}' contains more than one rule named '{rule.Name}'.");
+        }
+
+        return rules;
+    }
+
+    private bool EvaluateRules(List<IRule<TData>> rules, TData data, RootContext rootContext, ref RuleContext? currentContext)
+    {
+        foreach (var rule in rules)
+        {
+            currentContext = null;
 
             var ctx = new RuleContext
             {
@@ -64,18 +111,45 @@ public class RuleSet<TData>(
             };
 
             rootContext.SetChildContext(rule.Name, ctx);
+            currentContext = ctx;
+
+            // Counted once the rule has a context, so an exceeded budget is recorded against the rule it stopped
+            rootContext.CountStep(Name);
 
             _logger.LogInformation(
                 "[Evaluating] Rule={RuleName} for RuleSet={RuleSetName}",
                 rule.Name,
                 Name);
 
-            if (rule.Evaluate(data, rootContext))
+            var passed = rule.Evaluate(data, rootContext);
+
+            // Guarded rules skip themselves when their guard does not hold,
+            // and rules with an error policy when their evaluation raised an error
+            if (!ctx.Skipped)
+            {
+                ctx.Result = passed;
+                ctx.Output = passed ? rule.Success(data, rootContext) : rule.Failure(data, rootContext);
+            }
+
+            ctx.RuleDataAfterEvaluation = data.Clone();
+            ctx.Changes = RuleDataChange.Compare(ctx.RuleDataBeforeEvaluation, ctx.RuleDataAfterEvaluation);
+
+            // Error policies may also skip the rule, or replace its result, when its success or failure logic raised an error
+            if (ctx.Skipped)
             {
                 ctx.Result = true;
-                ctx.Output = rule.Success(data, rootContext);
-                ctx.RuleDataAfterEvaluation = data.Clone();
 
+                _logger.LogInformation(
+                    "[Rule Skipped] Rule={RuleName} Error={RuleError} for RuleSet={RuleSetName}",
+                    rule.Name,
+                    ctx.Error,
+                    Name);
+
+                continue;
+            }
+
+            if (ctx.Result)
+            {
                 _logger.LogInformation(
                     "[Rule Passed] Rule={RuleName} Output={RuleOutput} for RuleSet={RuleSetName}",
                     rule.Name,
@@ -89,10 +163,6 @@ public class RuleSet<TData>(
                 return true;
             }
 
-            ctx.Result = false;
-            ctx.Output = rule.Failure(data, rootContext);
-            ctx.RuleDataAfterEvaluation = data.Clone();
-
             _logger.LogInformation(
                 "[Rule Failed] Rule={RuleName} Output={RuleOutput} for RuleSet={RuleSetName}",
                 rule.Name,
@@ -118,6 +188,7 @@ public class RuleSet<TData>(
             return x.Value switch
             {
                 RuleContext ruleContext => ruleContext.Result,
+                RuleGroupContext groupContext => groupContext.Result,
                 // Recurse....
                 RootContext nestedContext => VerifyAllChildRuleContexts(nestedContext),
                 _ => true
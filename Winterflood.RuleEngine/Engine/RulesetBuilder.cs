@@ -12,7 +12,8 @@ namespace Winterflood.RuleEngine.Engine;
 public sealed class RuleSetBuilder<TData>
     where TData : class, IRuleData, new()
 {
-    private readonly List<Func<IRule<TData>>> _rules = [];
+    private readonly List<RegisteredRule> _rules = [];
+    private readonly Dictionary<string, Func<IRule<TData>>> _overrides = new();
     private readonly string _name;
     private readonly RuleExecutionMode _mode;
     private readonly ILoggerFactory _loggerFactory;
@@ -36,7 +37,7 @@ public sealed class RuleSetBuilder<TData>
     /// </summary>
     public RuleSetBuilder<TData> Add(IRule<TData> rule)
     {
-        _rules.Add(() => rule);
+        _rules.Add(new RegisteredRule(rule.Name, () => rule));
         return this;
     }
 
@@ -45,7 +46,7 @@ public sealed class RuleSetBuilder<TData>
     /// </summary>
     public RuleSetBuilder<TData> Add<TRule>() where TRule : IRule<TData>, new()
     {
-        _rules.Add(() => new TRule());
+        _rules.Add(new RegisteredRule(null, () => new TRule()));
         return this;
     }
 
@@ -54,18 +55,89 @@ public sealed class RuleSetBuilder<TData>
     /// </summary>
     public RuleSetBuilder<TData> Add(Func<IRule<TData>> factory)
     {
-        _rules.Add(factory);
+        _rules.Add(new RegisteredRule(null, factory));
+        return this;
+    }
+
+    /// <summary>
+    /// Adds a rule using a factory function, registering the name of the rule it creates so that the rule
+    /// can be replaced with <see cref="Override"/> without being created.
+    /// </summary>
+    /// <exception cref="InvalidOperationException">Thrown when the created rule is not named <paramref name="ruleName"/>.</exception>
+    public RuleSetBuilder<TData> Add(string ruleName, Func<IRule<TData>> factory)
+    {
+        _rules.Add(new RegisteredRule(ruleName, () =>
+        {
+            var rule = factory();
+            if (rule.Name != ruleName)
+                throw new InvalidOperationException($"Rule '{rule.Name}' in RuleSet '{_name}' was added as '{ruleName}'.");
+
+            return rule;
+        }));
+        return this;
+    }
+
+    /// <summary>
+    /// Adds every rule configured on another builder, preserving their order.
+    /// </summary>
+    public RuleSetBuilder<TData> Include(RuleSetBuilder<TData> other)
+    {
+        _rules.AddRange(other._rules);
+        return this;
+    }
+
+    /// <summary>
+    /// Replaces a rule added or included earlier with another rule, keeping its position.
+    /// </summary>
+    /// <remarks>
+    /// Rules are matched by the name they were added with, so only rules added as an instance or with
+    /// <see cref="Add(string, Func{IRule{TData}})"/> can be overridden, and no rule is created when the builder is built.
+    /// </remarks>
+    public RuleSetBuilder<TData> Override(string ruleName, Func<IRule<TData>> factory)
+    {
+        _overrides[ruleName] = factory;
         return this;
     }
 
     /// <summary>
     /// Builds the configured RuleSet.
     /// </summary>
+    /// <exception cref="InvalidOperationException">Thrown when an override names a rule that was not added or included by name.</exception>
     public RuleSet<TData> Build()
     {
         var ruleSet = new RuleSet<TData>(_name, _mode, _loggerFactory);
-        foreach (var ruleFactory in _rules)
+        foreach (var ruleFactory in ResolveOverrides())
             ruleSet.AddRule(ruleFactory);
         return ruleSet;
     }
+
+    private List<Func<IRule<TData>>> ResolveOverrides()
+    {
+        var resolved = new List<Func<IRule<TData>>>();
+        var unused = new HashSet<string>(_overrides.Keys);
+
+        foreach (var rule in _rules)
+        {
+            if (rule.Name is not null && _overrides.TryGetValue(rule.Name, out var replacement))
+            {
+                resolved.Add(replacement);
+                unused.Remove(rule.Name);
+            }
+            else
+            {
+                resolved.Add(rule.Factory);
+            }
+        }
+
+        if (unused.Count > 0)
+            throw new InvalidOperationException(
+                $"RuleSet '{_name}' overrides unknown rules: {string.Join(", ", unused.Order(StringComparer.Ordinal))}.");
+
+        return resolved;
+    }
+
+    /// <summary>
+    /// A rule added to the builder, with the name it was added as when that is known without creating it.
+    /// </summary>
+    private sealed record RegisteredRule(string? Name, Func<IRule<TData>> Factory);
 }
\ No newline at end of file
@@ -0,0 +1,33 @@
+namespace Winterflood.RuleEngine.Engine;
+
+/// <summary>
+/// Limits the work a single evaluation may do, so that large or deeply nested rulesets cannot run unbounded.
+/// </summary>
+/// <remarks>
+/// The limits apply to the whole evaluation, including nested rulesets, groups, branches and collection items.
+/// When a limit is exceeded the evaluation is aborted, and error policies do not handle the abort.
+/// </remarks>
+public class RunOptions
+{
+    /// <summary>
+    /// Gets the maximum number of rules that may be evaluated, or <c>null</c> for no limit.
+    /// </summary>
+    public int? MaxSteps { get; init; }
+
+    /// <summary>
+    /// Gets the time by which the evaluation must complete, or <c>null</c> for no deadline.
+    /// The deadline is checked before each rule is evaluated.
+    /// </summary>
+    public DateTimeOffset? Deadline { get; init; }
+
+    /// <summary>
+    /// Gets the clock the <see cref="Deadline"/> is checked against. Defaults to <see cref="TimeProvider.System"/>.
+    /// </summary>
+    public TimeProvider TimeProvider { get; init; } = TimeProvider.System;
+
+    /// <summary>
+    /// Gets the maximum depth of nested contexts a ruleset may be evaluated in, or <c>null</c> for no limit.
+    /// A ruleset evaluated directly has a depth of 0, and each nested ruleset, group, branch or collection item adds 1.
+    /// </summary>
+    public int? MaxNestingDepth { get; init; }
+}